@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate criterion;
+extern crate multistr;
+
+use criterion::{black_box, Criterion};
+use multistr::StringVec;
+
+fn make_strings() -> Vec<String> {
+    (0..100_000).map(|i| format!("s{}", i % 1000)).collect()
+}
+
+fn collect(c: &mut Criterion) {
+    let strings = make_strings();
+    c.bench_function("collect_100k", move |b| {
+        b.iter(|| black_box(&strings).iter().map(String::as_str).collect::<StringVec>())
+    });
+}
+
+fn push_without_reserve(c: &mut Criterion) {
+    let strings = make_strings();
+    c.bench_function("push_100k", move |b| {
+        b.iter(|| {
+            let mut vec = StringVec::new();
+            for s in black_box(&strings) {
+                vec.push(s);
+            }
+            vec
+        })
+    });
+}
+
+criterion_group!(benches, collect, push_without_reserve);
+criterion_main!(benches);
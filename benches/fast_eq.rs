@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate criterion;
+extern crate multistr;
+
+use criterion::{black_box, Criterion};
+use multistr::StringVec;
+
+fn make_vec() -> StringVec {
+    (0..10_000).map(|i| format!("string number {}", i)).collect()
+}
+
+fn fast_eq(c: &mut Criterion) {
+    let lhs = make_vec();
+    let rhs = lhs.clone();
+    c.bench_function("fast_eq", move |b| b.iter(|| black_box(&lhs).fast_eq(black_box(&rhs))));
+}
+
+fn element_wise_eq(c: &mut Criterion) {
+    let lhs = make_vec();
+    let rhs = lhs.clone();
+    c.bench_function("element_wise_eq", move |b| b.iter(|| black_box(&lhs) == black_box(&rhs)));
+}
+
+criterion_group!(benches, fast_eq, element_wise_eq);
+criterion_main!(benches);
@@ -1,245 +1,519 @@
-use std::borrow::BorrowMut;
+use std::borrow::{Borrow, BorrowMut};
 use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Index, IndexMut, Range, RangeTo, RangeFrom, RangeFull};
+use std::ops::{Index, IndexMut, Range, RangeBounds, RangeTo, RangeFrom, RangeFull};
 
 use bow::Bow;
+use extra_default::DefaultRef;
 use len_trait::{Len, SplitAtMut};
 use push_trait::PushBack;
 
-macro_rules! gen_impl {
-    ($($name:ident, $slice_name:ident, $str_name:ident, $c_str_name:ident, $os_str_name:ident, $n:expr,)*) => {
-        $(
-            /// Array of immutable strings stored on the heap in the same buffer.
-            pub struct $name<T: $crate::StrLike + ?Sized> {
-                buffer: ::bow::Bow<'static, T::Data>,
-                split: [usize; $n],
-            }
+use super::{DataConcat, Iter, IterMut, RawError, Split, SplitRange, StrLike, StrLikeMut};
 
-            impl<'a, T: $crate::StrLike + ?Sized> From<[&'a T; $n]> for $name<T> {
-                fn from(inner: [&'a T; $n]) -> $name<T> {
-                    $name::new(inner)
-                }
-            }
+/// Array of immutable strings stored on the heap in the same buffer.
+pub struct Static<T: StrLike + ?Sized, const N: usize> {
+    buffer: Bow<'static, T::Data>,
+    split: [usize; N],
+}
 
-            impl<T: $crate::StrLike + ?Sized> Default for $name<T> {
-                fn default() -> $name<T> {
-                    let def: &'static T = ::extra_default::DefaultRef::default_ref();
-                    let data = def.to_data();
-                    let len = data.len();
-                    let mut buffer = data.to_owned();
-
-                    let mut split = [len; $n];
-                    let mut acc = 0;
-                    for s in &mut split {
-                        *s = acc;
-                        acc += len;
-                        buffer.push_back(data);
-                    }
-                    $name { buffer: Bow::Boxed(buffer.into()), split }
-                }
-            }
+impl<'a, T: StrLike + ?Sized, const N: usize> From<[&'a T; N]> for Static<T, N> {
+    fn from(inner: [&'a T; N]) -> Static<T, N> {
+        Static::new(inner)
+    }
+}
 
-            impl<T: $crate::StrLike + ?Sized> $name<T> {
-                /// Creates a new `Static` from the given array of values.
-                pub fn new(inner: [&T; $n]) -> $name<T> {
-                    let inner: &[&T] = &inner;
+impl<'a, T: StrLike + ?Sized, const N: usize> IntoIterator for &'a Static<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
 
-                    let mut buffer: T::OwnedData = Default::default();
-                    for item in inner.iter() {
-                        buffer.push_back(item.to_data());
-                    }
-                    let buffer: Box<T::Data> = buffer.into();
-                    let buffer: Bow<'static, T::Data> = buffer.into();
-                    let mut split = [0; $n];
-                    inner.iter().map(|s| s.len()).enumerate().fold(0, |mut curr, (i, len)| {
-                        curr += len;
-                        split[i] = curr;
-                        curr
-                    });
-
-                    $name { buffer, split }
-                }
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
 
-                /// Creates a `Static` from its raw parts: a buffer and a list of split indices.
-                #[inline]
-                pub fn from_raw<D: Into<Bow<'static, T::Data>>>(buffer: D, split: [usize; $n]) -> $name<T> {
-                    let buffer = buffer.into();
-                    let check = $crate::Split::new(&split);
-                    check.check_valid(buffer.len())
-                        .unwrap_or_else(|e| panic!("split indices were invalid: {}", e));
-                    for idx in 0..$n {
-                        T::from_data(check.get(idx).index_into(&*buffer))
-                            .unwrap_or_else(|e| panic!("string {} was not valid: {}", idx, e));
-                    }
-                    $name { buffer, split }
-                }
+impl<T: StrLike + ?Sized, const N: usize> Default for Static<T, N> {
+    fn default() -> Static<T, N> {
+        let def: &'static T = DefaultRef::default_ref();
+        let data = def.to_data();
+        let len = data.len();
+        let mut buffer = data.to_owned();
+
+        let mut split = [len; N];
+        let mut acc = 0;
+        for s in &mut split {
+            *s = acc;
+            acc += len;
+            buffer.push_back(data);
+        }
+        Static { buffer: Bow::Boxed(buffer.into()), split }
+    }
+}
 
-                /// Creates a `Static` from its raw parts (unsafe version).
-                #[inline]
-                pub unsafe fn from_raw_unchecked<D: Into<Bow<'static, T::Data>>>(buffer: D, split: [usize; $n]) -> $name<T>
-                where T::Data: 'static
-                {
-                    let buffer = buffer.into();
-                    $name { buffer, split }
-                }
+impl<T: StrLike + ?Sized, const N: usize> Static<T, N> {
+    /// Creates a new `Static` from the given array of values.
+    pub fn new(inner: [&T; N]) -> Static<T, N> {
+        let inner: &[&T] = &inner;
+
+        let mut buffer: T::OwnedData = Default::default();
+        for item in inner.iter() {
+            buffer.push_back(item.to_data());
+        }
+        let buffer: Box<T::Data> = buffer.into();
+        let buffer: Bow<'static, T::Data> = buffer.into();
+        let mut split = [0; N];
+        inner.iter().map(|s| s.len()).enumerate().fold(0, |mut curr, (i, len)| {
+            curr += len;
+            split[i] = curr;
+            curr
+        });
+
+        Static { buffer, split }
+    }
 
-                /// Returns an iterator over the elements in this `Static`.
-                #[inline]
-                pub fn iter(&self) -> $crate::Iter<T> {
-                    $crate::Iter::new(&*self.buffer, &self.split)
-                }
-            }
+    /// Creates a `Static` from its raw parts: a buffer and a list of split indices.
+    #[inline]
+    pub fn from_raw<D: Into<Bow<'static, T::Data>>>(buffer: D, split: [usize; N]) -> Static<T, N> {
+        Static::new_checked(buffer, split).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-            impl<T: ?Sized + $crate::StrLike> Index<usize> for $name<T> {
-                type Output = T;
-                fn index(&self, index: usize) -> &T {
-                    assert_ne!(index, $n);
-                    unsafe {
-                        let split = $crate::Split::new(&self.split);
-                        T::from_data_unchecked(split.get(index).index_into(&self.buffer))
-                    }
-                }
-            }
+    /// Creates a `Static` from its raw parts, returning an error instead of panicking if the split
+    /// indices or any of the strings are invalid.
+    pub fn new_checked<D: Into<Bow<'static, T::Data>>>(buffer: D, split: [usize; N])
+        -> Result<Static<T, N>, RawError<T::ConvError>>
+    {
+        let buffer = buffer.into();
+        let check = Split::new(&split);
+        check.check_valid(buffer.len())?;
+        for idx in 0..N {
+            T::from_data(check.get(idx).index_into(&*buffer)).map_err(|e| RawError::Data(idx, e))?;
+        }
+        Ok(Static { buffer, split })
+    }
 
-            impl<T: ?Sized + $crate::StrLike + $crate::StrLikeMut> IndexMut<usize> for $name<T>
-                where T::Data: SplitAtMut<usize>,
-                      T::OwnedData: BorrowMut<T::Data>
-            {
-                #[inline]
-                fn index_mut(&mut self, index: usize) -> &mut T {
-                    assert_ne!(index, $n);
-                    unsafe {
-                        let idx = $crate::Split::new(&self.split).get(index);
-                        T::from_data_mut_unchecked(idx.index_into_mut(self.buffer.to_mut().borrow_mut()))
-                    }
-                }
-            }
+    /// Creates a `Static` from its raw parts (unsafe version).
+    #[inline]
+    pub unsafe fn from_raw_unchecked<D: Into<Bow<'static, T::Data>>>(buffer: D, split: [usize; N]) -> Static<T, N>
+    where T::Data: 'static
+    {
+        let buffer = buffer.into();
+        Static { buffer, split }
+    }
 
-            impl<T: ?Sized + $crate::DataConcat> Index<Range<usize>> for $name<T> {
-                type Output = T;
-                #[inline]
-                fn index(&self, range: Range<usize>) -> &T {
-                    unsafe {
-                        let split = $crate::Split::new(&self.split);
-                        T::from_data_unchecked(split.get_slice(range.into()).index_into(&self.buffer))
-                    }
-                }
+    /// Returns the element at the given index, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < N {
+            unsafe {
+                let split = Split::new(&self.split);
+                Some(T::from_data_unchecked(split.get(index).index_into(&self.buffer)))
             }
+        } else {
+            None
+        }
+    }
 
-            impl<T: ?Sized + $crate::DataConcat> Index<RangeFrom<usize>> for $name<T> {
-                type Output = T;
-                #[inline]
-                fn index(&self, range: RangeFrom<usize>) -> &T {
-                    unsafe {
-                        let split = $crate::Split::new(&self.split);
-                        T::from_data_unchecked(split.get_slice(range.into()).index_into(&self.buffer))
-                    }
-                }
-            }
+    /// Returns `true` iff one of the elements is equal to `needle`.
+    #[inline]
+    pub fn contains(&self, needle: &T) -> bool
+        where T: PartialEq
+    {
+        self.iter().any(|s| s == needle)
+    }
 
-            impl<T: ?Sized + $crate::DataConcat> Index<RangeTo<usize>> for $name<T> {
-                type Output = T;
-                #[inline]
-                fn index(&self, range: RangeTo<usize>) -> &T {
-                    unsafe {
-                        let split = $crate::Split::new(&self.split);
-                        T::from_data_unchecked(split.get_slice(range.into()).index_into(&self.buffer))
-                    }
-                }
-            }
+    /// Creates a new `Static` by applying `f` to each of the elements.
+    ///
+    /// The closure can return anything that borrows as a `U`, i.e. either a reference into the
+    /// element it's given, like `str::trim`, or an owned value, like `str::to_uppercase`. Results
+    /// are copied into the new array's buffer.
+    pub fn map<'a, U, R, F>(&'a self, f: F) -> Static<U, N>
+        where U: StrLike + ?Sized,
+              R: Borrow<U>,
+              F: FnMut(&'a T) -> R
+    {
+        let mapped: Vec<R> = self.iter().map(f).collect();
+        Static::from_borrowed(&mapped)
+    }
 
-            impl<T: ?Sized + $crate::DataConcat> Index<RangeFull> for $name<T> {
-                type Output = T;
-                #[inline]
-                fn index(&self, _: RangeFull) -> &T {
-                    unsafe {
-                        T::from_data_unchecked(&self.buffer)
-                    }
-                }
-            }
+    /// Creates a new `Static` from the first `N` items of a slice.
+    fn from_borrowed<R: Borrow<T>>(items: &[R]) -> Static<T, N> {
+        let mut inner: [&T; N] = [DefaultRef::default_ref(); N];
+        for (r, s) in inner.iter_mut().zip(items) {
+            *r = s.borrow();
+        }
+        Static::new(inner)
+    }
 
+    /// Returns an iterator over the elements in this `Static`.
+    #[inline]
+    pub fn iter(&self) -> Iter<T> {
+        Iter::new(&*self.buffer, &self.split)
+    }
 
-            impl<T: $crate::StrLike + ?Sized> Clone for $name<T>
-                where Box<T::Data>: Clone
-            {
-                fn clone(&self) -> $name<T> {
-                    $name {
-                        buffer: self.buffer.clone(),
-                        split: self.split.clone(),
-                    }
-                }
-                fn clone_from(&mut self, source: &$name<T>) {
-                    self.buffer.clone_from(&source.buffer);
-                    self.split.clone_from(&source.split);
-                }
-            }
+    /// Reverses the order of the elements.
+    pub fn reverse(&mut self) {
+        let mut order = [0; N];
+        for (idx, o) in order.iter_mut().enumerate() {
+            *o = N - 1 - idx;
+        }
+        self.permute(order);
+    }
 
-            impl<T: $crate::StrLike + ?Sized> ::std::hash::Hash for $name<T>
-                where T::Data: ::std::hash::Hash
-            {
-                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
-                    self.buffer.hash(state);
-                    self.split.hash(state);
-                }
-            }
+    /// Rotates the elements so that the element at `mid` becomes the first one, like
+    /// `slice::rotate_left`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than `N`.
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= N, "index {} was out of bounds", mid);
+        let mut order = [0; N];
+        for (idx, o) in order.iter_mut().enumerate() {
+            *o = (idx + mid) % N;
+        }
+        self.permute(order);
+    }
 
-            impl<T: $crate::StrLike + PartialEq + ?Sized> PartialEq for $name<T> {
-                fn eq(&self, rhs: &$name<T>) -> bool {
-                    self.iter().eq(rhs.iter())
-                }
-            }
+    /// Rebuilds the buffer so that the `i`th element is the `order[i]`th element of the original.
+    fn permute(&mut self, order: [usize; N]) {
+        let mut buffer: T::OwnedData = Default::default();
+        let mut split = [0; N];
+        for (end, &idx) in split.iter_mut().zip(&order) {
+            buffer.push_back(self[idx].to_data());
+            *end = buffer.len();
+        }
+        self.buffer = Bow::Boxed(buffer.into());
+        self.split = split;
+    }
+}
 
-            impl<T: $crate::StrLike + Eq + ?Sized> Eq for $name<T> {}
+impl<T: StrLike + ?Sized> Static<T, 2> {
+    /// Exchanges the two elements.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.reverse()
+    }
+}
 
-            impl<T: $crate::StrLike + PartialOrd + ?Sized> PartialOrd for $name<T> {
-                fn partial_cmp(&self, rhs: &$name<T>) -> Option<Ordering> {
-                    self.iter().partial_cmp(rhs.iter())
-                }
-                fn lt(&self, rhs: &$name<T>) -> bool {
-                    self.iter().lt(rhs.iter())
-                }
-                fn le(&self, rhs: &$name<T>) -> bool {
-                    self.iter().le(rhs.iter())
-                }
-                fn gt(&self, rhs: &$name<T>) -> bool {
-                    self.iter().gt(rhs.iter())
-                }
-                fn ge(&self, rhs: &$name<T>) -> bool {
-                    self.iter().ge(rhs.iter())
-                }
-            }
+impl<const N: usize> Static<str, N> {
+    /// Splits a string on the first few occurrences of `delim`, one for each string but the last.
+    ///
+    /// Any further occurrences of `delim` are kept in the last string. Returns `None` if
+    /// there aren't enough occurrences of `delim` to fill every string, or if there are no
+    /// strings at all.
+    pub fn from_delimited(s: &str, delim: char) -> Option<Static<str, N>> {
+        if N == 0 {
+            return None;
+        }
+        let mut buffer = String::with_capacity(s.len());
+        let mut split = [0; N];
+        let mut rest = s;
+        for idx in &mut split[..N - 1] {
+            let pos = rest.find(delim)?;
+            buffer.push_str(&rest[..pos]);
+            *idx = buffer.len();
+            rest = &rest[pos + delim.len_utf8()..];
+        }
+        buffer.push_str(rest);
+        split[N - 1] = buffer.len();
+
+        let buffer: Box<[u8]> = buffer.into_bytes().into_boxed_slice();
+        Some(Static { buffer: buffer.into(), split })
+    }
+}
 
-            impl<T: $crate::StrLike + Ord + ?Sized> Ord for $name<T> {
-                fn cmp(&self, rhs: &$name<T>) -> Ordering {
-                    self.iter().cmp(rhs.iter())
-                }
+impl Static<str, 2> {
+    /// Splits a string in two at the first occurrence of `delimiter`, dropping the delimiter.
+    ///
+    /// Returns `None` if the delimiter doesn't occur. This is the same as `from_delimited`.
+    #[inline]
+    pub fn from_str_split(s: &str, delimiter: char) -> Option<Static<str, 2>> {
+        Static::from_delimited(s, delimiter)
+    }
+
+    /// Returns both strings at once.
+    #[inline]
+    pub fn as_tuple(&self) -> (&str, &str) {
+        (&self[0], &self[1])
+    }
+
+    /// Converts the pair into two owned strings.
+    ///
+    /// The first string reuses the buffer, so only the second one is allocated.
+    pub fn into_strings(self) -> (String, String) {
+        let mut left = match self.buffer {
+            Bow::Boxed(buffer) => buffer.into_vec(),
+            Bow::Borrowed(buffer) => buffer.to_vec(),
+        };
+        let mut right = left.split_off(self.split[0]);
+        right.truncate(self.split[1] - self.split[0]);
+        unsafe { (String::from_utf8_unchecked(left), String::from_utf8_unchecked(right)) }
+    }
+}
+
+impl<T: ?Sized + StrLike, const N: usize> Index<usize> for Static<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| panic!("index {} was out of bounds", index))
+    }
+}
+
+impl<T: ?Sized + StrLike + StrLikeMut, const N: usize> Static<T, N>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{
+    /// Returns an iterator over mutable references to the elements in this `Static`.
+    ///
+    /// The first mutation copies the buffer if it's still borrowed.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut::new(self.buffer.to_mut().borrow_mut(), &self.split, 0)
+    }
+}
+
+impl<T: ?Sized + StrLike + StrLikeMut, const N: usize> IndexMut<usize> for Static<T, N>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        assert_ne!(index, N);
+        unsafe {
+            let idx = Split::new(&self.split).get(index);
+            T::from_data_mut_unchecked(idx.index_into_mut(self.buffer.to_mut().borrow_mut()))
+        }
+    }
+}
+
+impl<T: ?Sized + DataConcat, const N: usize> Static<T, N> {
+    /// Returns all of the strings concatenated together, like `&array[..]`.
+    ///
+    /// Since the strings share a buffer, this doesn't copy anything.
+    #[inline]
+    pub fn concat(&self) -> &T {
+        unsafe { T::from_data_unchecked(&self.buffer) }
+    }
+
+    /// Checks whether two arrays hold the same data, regardless of where it's split
+    /// into strings.
+    #[inline]
+    pub fn content_eq(&self, other: &Static<T, N>) -> bool
+        where T: PartialEq
+    {
+        self.concat() == other.concat()
+    }
+
+    /// Returns the strings in the given range concatenated together.
+    ///
+    /// Unlike indexing, this accepts any kind of range, including `(Bound<usize>, Bound<usize>)`.
+    #[inline]
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> &T {
+        unsafe {
+            let split = Split::new(&self.split);
+            let range = SplitRange::from_bounds(range);
+            T::from_data_unchecked(split.get_slice(range).index_into(&self.buffer))
+        }
+    }
+}
+
+impl<T: ?Sized + DataConcat, const N: usize> Index<Range<usize>> for Static<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: Range<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat, const N: usize> Index<RangeFrom<usize>> for Static<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeFrom<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat, const N: usize> Index<RangeTo<usize>> for Static<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeTo<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat, const N: usize> Index<RangeFull> for Static<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeFull) -> &T {
+        self.range(range)
+    }
+}
+
+
+impl<T: StrLike + ?Sized, const N: usize> Clone for Static<T, N>
+    where Box<T::Data>: Clone
+{
+    fn clone(&self) -> Static<T, N> {
+        Static {
+            buffer: self.buffer.clone(),
+            split: self.split.clone(),
+        }
+    }
+    fn clone_from(&mut self, source: &Static<T, N>) {
+        self.buffer.clone_from(&source.buffer);
+        self.split.clone_from(&source.split);
+    }
+}
+
+impl<T: StrLike + ?Sized, const N: usize> ::std::hash::Hash for Static<T, N>
+    where T::Data: ::std::hash::Hash
+{
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        self.buffer.hash(state);
+        self.split.hash(state);
+    }
+}
+
+impl<T: StrLike + PartialEq + ?Sized, const N: usize> PartialEq for Static<T, N> {
+    fn eq(&self, rhs: &Static<T, N>) -> bool {
+        self.iter().eq(rhs.iter())
+    }
+}
+
+impl<T: StrLike + Eq + ?Sized, const N: usize> Eq for Static<T, N> {}
+
+impl<T: StrLike + PartialOrd + ?Sized, const N: usize> PartialOrd for Static<T, N> {
+    fn partial_cmp(&self, rhs: &Static<T, N>) -> Option<Ordering> {
+        self.iter().partial_cmp(rhs.iter())
+    }
+    fn lt(&self, rhs: &Static<T, N>) -> bool {
+        self.iter().lt(rhs.iter())
+    }
+    fn le(&self, rhs: &Static<T, N>) -> bool {
+        self.iter().le(rhs.iter())
+    }
+    fn gt(&self, rhs: &Static<T, N>) -> bool {
+        self.iter().gt(rhs.iter())
+    }
+    fn ge(&self, rhs: &Static<T, N>) -> bool {
+        self.iter().ge(rhs.iter())
+    }
+}
+
+impl<T: StrLike + Ord + ?Sized, const N: usize> Ord for Static<T, N> {
+    fn cmp(&self, rhs: &Static<T, N>) -> Ordering {
+        self.iter().cmp(rhs.iter())
+    }
+}
+
+impl<T: StrLike + fmt::Debug + ?Sized, const N: usize> fmt::Debug for Static<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for Static<str, N> {
+    /// Writes the strings concatenated together, straight from the buffer.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.concat(), f)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: StrLike + ?Sized, const N: usize> ::quickcheck::Arbitrary for Static<T, N>
+    where T::Owned: ::quickcheck::Arbitrary,
+          Static<T, N>: Clone + Send
+{
+    fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> Static<T, N> {
+        let owned: Vec<<T as ToOwned>::Owned> = (0..N).map(|_| ::quickcheck::Arbitrary::arbitrary(g)).collect();
+        Static::from_borrowed(&owned)
+    }
+
+    /// Shrinks one element at a time, keeping the others as they are.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Static<T, N>>> {
+        let owned: Vec<<T as ToOwned>::Owned> = self.iter().map(ToOwned::to_owned).collect();
+        Box::new((0..N).flat_map(move |idx| {
+            let owned = owned.clone();
+            owned[idx].shrink().map(move |s| {
+                let mut owned = owned.clone();
+                owned[idx] = s;
+                Static::from_borrowed(&owned)
+            })
+        }))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: StrLike + ::serde::Serialize + ?Sized, const N: usize> ::serde::Serialize for Static<T, N> {
+    /// Serializes the array as a tuple of strings.
+    fn serialize<Z: ::serde::Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for s in self.iter() {
+            tuple.serialize_element(s)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: StrLike + ?Sized, const N: usize> ::serde::Deserialize<'de> for Static<T, N>
+    where T::Owned: ::serde::Deserialize<'de>
+{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Static<T, N>, D::Error> {
+        struct Visitor<T: ?Sized, const N: usize>(::std::marker::PhantomData<fn() -> Box<T>>);
+
+        impl<'de, T: StrLike + ?Sized, const N: usize> ::serde::de::Visitor<'de> for Visitor<T, N>
+            where T::Owned: ::serde::Deserialize<'de>
+        {
+            type Value = Static<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a tuple of {} strings", N)
             }
 
-            impl<T: $crate::StrLike + fmt::Debug + ?Sized> fmt::Debug for $name<T> {
-                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                    f.debug_list()
-                        .entries(self.iter())
-                        .finish()
+            fn visit_seq<A: ::serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Static<T, N>, A::Error> {
+                let mut owned = Vec::with_capacity(N);
+                for idx in 0..N {
+                    match seq.next_element::<<T as ToOwned>::Owned>()? {
+                        Some(s) => owned.push(s),
+                        None => return Err(::serde::de::Error::invalid_length(idx, &self)),
+                    }
                 }
+                Ok(Static::from_borrowed(&owned))
             }
+        }
+
+        deserializer.deserialize_tuple(N, Visitor(::std::marker::PhantomData))
+    }
+}
+
+macro_rules! gen_aliases {
+    ($($name:ident, $slice_name:ident, $str_name:ident, $c_str_name:ident, $os_str_name:ident, $n:expr,)*) => {
+        $(
+            /// Array of immutable strings stored on the heap in the same buffer.
+            pub type $name<T> = Static<T, $n>;
 
             /// Array of immutable slices stored on the heap in the same buffer.
-            pub type $slice_name<T: 'static + Copy> = $name<[T]>;
+            pub type $slice_name<T> = Static<[T], $n>;
 
             /// Array of immutable `str`s stored on the heap in the same buffer.
-            pub type $str_name = $name<str>;
+            pub type $str_name = Static<str, $n>;
 
             /// Array of immutable `CStr`s stored on the heap in the same buffer.
-            pub type $c_str_name = $name<::std::ffi::CStr>;
+            pub type $c_str_name = Static<::std::ffi::CStr, $n>;
 
-            ///// Array of immutable `OsStr`s stored on the heap in the same buffer.
-            //pub type $os_str_name = $name<::std::ffi::OsStr>;
+            /// Array of immutable `OsStr`s stored on the heap in the same buffer.
+            #[cfg(unix)]
+            pub type $os_str_name = Static<::std::ffi::OsStr, $n>;
         )*
     }
 }
 
-gen_impl! {
+gen_aliases! {
     Static2, SliceArray2, StringArray2, CStringArray2, OsStringArray2, 2,
     Static3, SliceArray3, StringArray3, CStringArray3, OsStringArray3, 3,
     Static4, SliceArray4, StringArray4, CStringArray4, OsStringArray4, 4,
@@ -261,7 +535,7 @@ gen_impl! {
 mod tests {
     use std::ffi::CStr;
 
-    use super::Static3;
+    use super::{Static, Static2, Static3, StringArray2, StringArray3};
 
     #[test]
     fn debug() {
@@ -290,6 +564,31 @@ mod tests {
         let _ = &array[3];
     }
 
+    #[test]
+    fn get() {
+        let array = Static3::new(["English", "Français", "中文"]);
+        assert_eq!(array.get(1), Some("Français"));
+        assert_eq!(array.get(3), None);
+        assert_eq!(array.get(usize::max_value()), None);
+        assert_eq!((0..3).filter_map(|i| array.get(i)).collect::<Vec<_>>(), vec!["English", "Français", "中文"]);
+        assert_eq!(<Static2<CStr>>::default().get(2), None);
+    }
+
+    #[test]
+    fn contains() {
+        let array = Static3::new(["English", "Français", "中文"]);
+        assert!(array.contains("中文"));
+        assert!(!array.contains("Deutsch"));
+        assert!(!array.contains(""));
+        assert!(Static2::new(["", "English"]).contains(""));
+    }
+
+    #[test]
+    fn iter_rev() {
+        let array = Static3::new(["English", "Français", "中文"]);
+        assert_eq!(array.iter().rev().collect::<Vec<_>>(), vec!["中文", "Français", "English"]);
+    }
+
     #[test]
     fn index() {
         let array = Static3::new(["English", "Français", "中文"]);
@@ -317,6 +616,259 @@ mod tests {
         assert_eq!(&array[..], "EnglishFrançais中文");
     }
 
+    #[test]
+    fn range() {
+        use std::ops::Bound::*;
+
+        let array = Static3::new(["English", "Français", "中文"]);
+        assert_eq!(array.range(0..2), &array[0..2]);
+        assert_eq!(array.range(1..), &array[1..]);
+        assert_eq!(array.range(..2), &array[..2]);
+        assert_eq!(array.range(..), &array[..]);
+        assert_eq!(array.range((Included(1), Excluded(3))), &array[1..3]);
+        assert_eq!(array.range((Excluded(0), Included(1))), &array[1..2]);
+        assert_eq!(array.range((Unbounded, Included(0))), &array[..1]);
+        assert_eq!(array.range((Excluded(1), Unbounded)), &array[2..]);
+    }
+
+    #[test]
+    fn concat() {
+        let array = Static3::new(["English", "Français", "中文"]);
+        assert_eq!(array.concat(), "EnglishFrançais中文");
+        assert_eq!(array.concat(), &array[..]);
+        assert_eq!(Static2::new([&b""[..], &b""[..]]).concat(), b"");
+    }
+
+    #[test]
+    fn content_eq() {
+        let array = Static2::new(["Eng", "lish"]);
+        assert!(array.content_eq(&Static2::new(["English", ""])));
+        assert!(array.content_eq(&array));
+        assert!(!array.content_eq(&Static2::new(["Eng", "lisch"])));
+        assert_ne!(array, Static2::new(["English", ""]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use serde_json;
+
+        let pair = StringArray2::new(["clé", "valeur"]);
+        let json = serde_json::to_string(&pair).unwrap();
+        assert_eq!(json, r#"["clé","valeur"]"#);
+        assert_eq!(serde_json::from_str::<StringArray2>(&json).unwrap(), pair);
+
+        let triple = StringArray3::new(["", "b", ""]);
+        let json = serde_json::to_string(&triple).unwrap();
+        assert_eq!(json, r#"["","b",""]"#);
+        assert_eq!(serde_json::from_str::<StringArray3>(&json).unwrap(), triple);
+
+        let (a, b): (String, String) = serde_json::from_str(&serde_json::to_string(&pair).unwrap()).unwrap();
+        assert_eq!((&*a, &*b), ("clé", "valeur"));
+        assert!(serde_json::from_str::<StringArray3>(r#"["a","b"]"#).is_err());
+        assert!(serde_json::from_str::<StringArray2>(r#"["a","b","c"]"#).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        use super::OsStringArray2;
+
+        let array = OsStringArray2::new([OsStr::new("clé"), OsStr::from_bytes(b"\xff")]);
+        assert_eq!(&array[0], OsStr::new("clé"));
+        assert_eq!(array[1].as_bytes(), b"\xff");
+    }
+
+    #[test]
+    fn any_size() {
+        let strings = ["a"; 17];
+        let array = Static::new(strings);
+        assert_eq!(&array[16], "a");
+        assert_eq!(&array[..], "a".repeat(17));
+        assert!(array.iter().eq(strings.iter().cloned()));
+
+        let array = <Static<str, 1>>::from_delimited("a,b", ',').unwrap();
+        assert_eq!(&array[0], "a,b");
+        assert_eq!(<Static<str, 0>>::default().iter().next(), None);
+        assert_eq!(<Static<str, 0>>::from_delimited("a", ','), None);
+
+        let array: StringArray3 = Static::new(["English", "Français", "中文"]);
+        assert_eq!(array, Static3::new(["English", "Français", "中文"]));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut array = Static::new(["abc", "", "déf", "g"]);
+        for s in array.iter_mut() {
+            s.make_ascii_uppercase();
+        }
+        assert_eq!(array, Static::new(["ABC", "", "DéF", "G"]));
+        assert_eq!(array.iter_mut().len(), 4);
+
+        let mut array = Static2::new([&b"ab"[..], &b"cd"[..]]);
+        let mut iter = array.iter_mut();
+        let (first, second) = (iter.next().unwrap(), iter.next().unwrap());
+        first[1] = b'x';
+        second[0] = b'y';
+        assert_eq!(iter.next(), None);
+        assert_eq!(&array[..], b"axyd");
+    }
+
+    #[test]
+    fn new_checked() {
+        use super::super::{RawError, SplitError};
+
+        let array = Static2::<str>::new_checked(&b"cl\xc3\xa9valeur"[..], [4, 10]).unwrap();
+        assert_eq!(array, Static2::new(["clé", "valeur"]));
+
+        match Static3::<str>::new_checked(&b"abc"[..], [2, 1, 3]) {
+            Err(RawError::Split(SplitError::NotMonotonic(2, 1))) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match Static2::<str>::new_checked(&b"abc"[..], [1, 4]) {
+            Err(RawError::Split(SplitError::OutOfBounds(4))) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match Static2::<str>::new_checked(&b"cl\xc3\xa9"[..], [3, 4]) {
+            Err(RawError::Data(0, _)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_from_raw_invalid() {
+        Static2::<str>::from_raw(&b"abc"[..], [2, 1]);
+    }
+
+    #[test]
+    fn map() {
+        let array = Static3::new([" English ", "Français ", " 中文"]);
+        assert_eq!(array.map(str::trim), Static3::new(["English", "Français", "中文"]));
+        assert_eq!(array.map(|s| s.trim().to_uppercase()), Static3::new(["ENGLISH", "FRANÇAIS", "中文"]));
+        assert_eq!(array.map(str::as_bytes), Static3::new([&b" English "[..], "Français ".as_bytes(), " 中文".as_bytes()]));
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn shrink() {
+        use quickcheck::Arbitrary;
+
+        let array = Static2::<str>::new(["ab", ""]);
+        let shrunk = array.shrink().collect::<Vec<_>>();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|s| &s[0] != "ab" && &s[1] == ""));
+        assert!(shrunk.contains(&Static2::new(["", ""])));
+    }
+
+    #[cfg(feature = "quickcheck")]
+    quickcheck! {
+        fn arbitrary_pair(array: Static2<str>) -> bool {
+            array.iter().count() == 2
+        }
+    }
+
+    #[test]
+    fn pair_tuple() {
+        let pair = StringArray2::new(["clé", "valeur"]);
+        assert_eq!(pair.as_tuple(), ("clé", "valeur"));
+        assert_eq!(pair.into_strings(), ("clé".to_string(), "valeur".to_string()));
+
+        let pair = StringArray2::new(["", "valeur"]);
+        assert_eq!(pair.as_tuple(), ("", "valeur"));
+        assert_eq!(pair.into_strings(), (String::new(), "valeur".to_string()));
+
+        let pair = StringArray2::from_raw(&b"clevaleur!"[..], [3, 9]);
+        assert_eq!(pair.into_strings(), ("cle".to_string(), "valeur".to_string()));
+        assert_eq!(StringArray2::default().into_strings(), (String::new(), String::new()));
+    }
+
+    #[test]
+    fn from_str_split() {
+        assert_eq!(StringArray2::from_str_split("key=value", '=').unwrap().as_tuple(), ("key", "value"));
+        assert_eq!(StringArray2::from_str_split("a=b=c", '=').unwrap().as_tuple(), ("a", "b=c"));
+        assert_eq!(StringArray2::from_str_split("=value", '=').unwrap().as_tuple(), ("", "value"));
+        assert_eq!(StringArray2::from_str_split("key=", '=').unwrap().as_tuple(), ("key", ""));
+        assert_eq!(StringArray2::from_str_split("key", '='), None);
+        assert_eq!(StringArray2::from_str_split("", '='), None);
+    }
+
+    #[test]
+    fn swap_reverse_rotate() {
+        let mut pair = StringArray2::new(["clé", "valeur"]);
+        assert!(pair < StringArray2::new(["valeur", "clé"]));
+        pair.swap();
+        assert_eq!(pair.as_tuple(), ("valeur", "clé"));
+        assert_eq!(&pair[..], "valeurclé");
+        assert!(pair > StringArray2::new(["clé", "valeur"]));
+        pair.swap();
+        assert_eq!(pair, StringArray2::new(["clé", "valeur"]));
+
+        let mut triple = StringArray3::new(["English", "", "中文"]);
+        triple.reverse();
+        assert_eq!(triple, StringArray3::new(["中文", "", "English"]));
+        assert_eq!(&triple[..], "中文English");
+        triple.rotate_left(1);
+        assert_eq!(triple, StringArray3::new(["", "English", "中文"]));
+        triple.rotate_left(3);
+        assert_eq!(triple, StringArray3::new(["", "English", "中文"]));
+        triple.rotate_left(0);
+        assert_eq!(triple, StringArray3::new(["", "English", "中文"]));
+        assert_eq!(&triple[1..], "English中文");
+    }
+
+    #[test]
+    fn display() {
+        let pair = StringArray2::new(["clé", "valeur"]);
+        assert_eq!(pair.to_string(), "clévaleur");
+        assert_eq!(pair.to_string(), pair[0].to_string() + &pair[1]);
+        let triple = StringArray3::new(["English", "", "中文"]);
+        assert_eq!(triple.to_string(), "English中文");
+        assert_eq!(format!("{:>12}", triple), "   English中文");
+    }
+
+    #[test]
+    fn into_iter() {
+        fn join<'a, I: IntoIterator<Item = &'a str>>(iter: I) -> String {
+            iter.into_iter().collect::<Vec<_>>().join("/")
+        }
+
+        let pair = StringArray2::new(["clé", "valeur"]);
+        assert_eq!((&pair).into_iter().collect::<Vec<_>>(), vec!["clé", "valeur"]);
+        assert_eq!(join(&pair), "clé/valeur");
+
+        let triple = StringArray3::new(["English", "", "中文"]);
+        let mut collected = Vec::new();
+        for s in &triple {
+            collected.push(s);
+        }
+        assert_eq!(collected, vec!["English", "", "中文"]);
+        assert_eq!(join(triple.iter()), "English//中文");
+    }
+
+    #[test]
+    fn pair_map() {
+        let pair = StringArray2::new(["clé", "Valeur"]);
+        let upper: StringArray2 = pair.map(str::to_uppercase);
+        assert_eq!(upper.as_tuple(), ("CLÉ", "VALEUR"));
+        assert_eq!(pair.map(str::to_lowercase), StringArray2::new(["clé", "valeur"]));
+    }
+
+    #[test]
+    fn from_delimited() {
+        let array = Static3::from_delimited("a,b,c,d", ',').unwrap();
+        assert_eq!(array, Static3::new(["a", "b", "c,d"]));
+        let array = Static3::from_delimited(",,", ',').unwrap();
+        assert_eq!(array, Static3::new(["", "", ""]));
+        let array = Static2::from_delimited("clé→valeur", '→').unwrap();
+        assert_eq!(array, Static2::new(["clé", "valeur"]));
+        assert_eq!(Static3::from_delimited("a,b", ','), None);
+        assert_eq!(Static2::from_delimited("ab", ','), None);
+    }
+
     #[test]
     #[should_panic]
     fn panic_left_oob() {
@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Index, Range, RangeBounds, RangeFrom, RangeFull, RangeTo};
+
+use super::{DataConcat, Dynamic, Iter, Split, SplitRange, StrLike};
+
+/// Immutable version of `Dynamic` which holds no spare capacity.
+///
+/// Useful for long-lived vectors which are no longer modified.
+pub struct FrozenDynamic<T: StrLike + ?Sized> {
+    buffer: Box<T::Data>,
+    split: Box<[usize]>,
+}
+
+impl<T: StrLike + ?Sized> FrozenDynamic<T> {
+    pub(crate) fn from_parts(buffer: Box<T::Data>, split: Box<[usize]>) -> FrozenDynamic<T> {
+        FrozenDynamic { buffer, split }
+    }
+
+    /// Returns the number of strings in the vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.split.len()
+    }
+
+    /// Returns `true` iff the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.split.is_empty()
+    }
+
+    /// Returns the string at the given index, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            unsafe {
+                let split = Split::new(&*self.split);
+                Some(T::from_data_unchecked(split.get(index).index_into(&*self.buffer)))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the strings in the vector.
+    #[inline]
+    pub fn iter(&self) -> Iter<T> {
+        Iter::new(&*self.buffer, &*self.split)
+    }
+}
+
+impl<T: ?Sized + DataConcat> FrozenDynamic<T> {
+    /// Returns the strings in the given range concatenated together.
+    ///
+    /// Unlike indexing, this accepts any kind of range, including `(Bound<usize>, Bound<usize>)`.
+    #[inline]
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> &T {
+        unsafe {
+            let split = Split::new(&*self.split);
+            T::from_data_unchecked(split.get_slice(SplitRange::from_bounds(range)).index_into(&*self.buffer))
+        }
+    }
+}
+
+impl<T: StrLike + ?Sized> From<Dynamic<T>> for FrozenDynamic<T> {
+    #[inline]
+    fn from(vec: Dynamic<T>) -> FrozenDynamic<T> {
+        vec.into_frozen()
+    }
+}
+
+impl<'a, T: StrLike + ?Sized> IntoIterator for &'a FrozenDynamic<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: ?Sized + StrLike> Index<usize> for FrozenDynamic<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| panic!("index {} was out of bounds", index))
+    }
+}
+
+impl<T: ?Sized + DataConcat> Index<Range<usize>> for FrozenDynamic<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: Range<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat> Index<RangeFrom<usize>> for FrozenDynamic<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeFrom<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat> Index<RangeTo<usize>> for FrozenDynamic<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeTo<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat> Index<RangeFull> for FrozenDynamic<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeFull) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + StrLike> Clone for FrozenDynamic<T>
+    where Box<T::Data>: Clone
+{
+    fn clone(&self) -> FrozenDynamic<T> {
+        FrozenDynamic {
+            buffer: self.buffer.clone(),
+            split: self.split.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized + StrLike + PartialEq> PartialEq for FrozenDynamic<T> {
+    fn eq(&self, rhs: &FrozenDynamic<T>) -> bool {
+        self.iter().eq(rhs.iter())
+    }
+}
+
+impl<T: ?Sized + StrLike + Eq> Eq for FrozenDynamic<T> {}
+
+impl<T: ?Sized + StrLike + PartialOrd> PartialOrd for FrozenDynamic<T> {
+    fn partial_cmp(&self, rhs: &FrozenDynamic<T>) -> Option<Ordering> {
+        self.iter().partial_cmp(rhs.iter())
+    }
+}
+
+impl<T: ?Sized + StrLike + Ord> Ord for FrozenDynamic<T> {
+    fn cmp(&self, rhs: &FrozenDynamic<T>) -> Ordering {
+        self.iter().cmp(rhs.iter())
+    }
+}
+
+impl<T: ?Sized + StrLike + fmt::Debug> fmt::Debug for FrozenDynamic<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Dynamic;
+
+    #[test]
+    fn round_trip() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let frozen = vec.clone().into_frozen();
+        assert_eq!(frozen.len(), 3);
+        assert!(frozen.iter().eq(vec.iter()));
+        assert_eq!(frozen.get(1), Some("Français"));
+        assert_eq!(frozen.get(3), None);
+        assert_eq!(&frozen[2], "中文");
+        assert_eq!(&frozen[1..], "Français中文");
+        assert_eq!(&frozen[..], "EnglishFrançais中文");
+    }
+
+    #[test]
+    fn empty() {
+        let frozen = <Dynamic<str>>::new().into_frozen();
+        assert!(frozen.is_empty());
+        assert_eq!(frozen.iter().next(), None);
+        assert_eq!(&frozen[..], "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_oob() {
+        let frozen = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>().into_frozen();
+        let _ = &frozen[3];
+    }
+}
@@ -1,10 +1,16 @@
-use super::{Split, StrLike};
+use std::borrow::{BorrowMut, Cow};
+use std::iter::FusedIterator;
+
+use len_trait::SplitAtMut;
+
+use super::{Dynamic, Split, Storage, StrLike, StrLikeMut};
 
 /// Iterator over `Dynamic` and `Static` types.
 pub struct Iter<'a, T: 'a + StrLike + ?Sized> {
     buffer: &'a T::Data,
     split: Split<'a>,
     idx: usize,
+    back: usize,
 }
 impl<'a, T: 'a + StrLike + ?Sized> Iter<'a, T> {
     pub(crate) fn new(buffer: &'a T::Data, split: &'a [usize]) -> Iter<'a, T> {
@@ -12,6 +18,7 @@ impl<'a, T: 'a + StrLike + ?Sized> Iter<'a, T> {
             buffer: buffer,
             split: Split::new(split),
             idx: 0,
+            back: split.len(),
         }
     }
 }
@@ -22,6 +29,7 @@ impl<'a, T: 'a + StrLike + ?Sized> Clone for Iter<'a, T> {
             buffer: self.buffer,
             split: self.split,
             idx: self.idx,
+            back: self.back,
         }
     }
 }
@@ -29,7 +37,7 @@ impl<'a, T: 'a + StrLike + ?Sized> Clone for Iter<'a, T> {
 impl<'a, T: 'a + StrLike + ?Sized> Iterator for Iter<'a, T> {
     type Item = &'a T;
     fn next(&mut self) -> Option<&'a T> {
-        if self.idx < self.split.len() {
+        if self.idx < self.back {
             let ret = unsafe {
                 T::from_data_unchecked(self.split.get(self.idx).index_into(self.buffer))
             };
@@ -39,4 +47,135 @@ impl<'a, T: 'a + StrLike + ?Sized> Iterator for Iter<'a, T> {
             None
         }
     }
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        if n < self.back - self.idx {
+            self.idx += n;
+            self.next()
+        } else {
+            self.idx = self.back;
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a + StrLike + ?Sized> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.idx < self.back {
+            let ret = unsafe {
+                T::from_data_unchecked(self.split.get(self.back - 1).index_into(self.buffer))
+            };
+            self.back -= 1;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: 'a + StrLike + ?Sized> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: 'a + StrLike + ?Sized> FusedIterator for Iter<'a, T> {}
+
+/// Mutable iterator over `Dynamic` types.
+pub struct IterMut<'a, T: 'a + StrLike + ?Sized> {
+    buffer: Option<&'a mut T::Data>,
+    split: &'a [usize],
+    offset: usize,
+}
+impl<'a, T: 'a + StrLike + ?Sized> IterMut<'a, T> {
+    /// Creates an iterator over a buffer which starts at `offset` in the original.
+    pub(crate) fn new(buffer: &'a mut T::Data, split: &'a [usize], offset: usize) -> IterMut<'a, T> {
+        IterMut {
+            buffer: Some(buffer),
+            split: split,
+            offset: offset,
+        }
+    }
+}
+
+impl<'a, T: 'a + StrLikeMut + ?Sized> Iterator for IterMut<'a, T>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<&'a mut T> {
+        let (&end, rest) = self.split.split_first()?;
+        let (head, tail) = self.buffer.take().unwrap().split_at_mut(end - self.offset);
+        self.buffer = Some(tail);
+        self.split = rest;
+        self.offset = end;
+        Some(unsafe { T::from_data_mut_unchecked(head) })
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.split.len(), Some(self.split.len()))
+    }
 }
+
+impl<'a, T: 'a + StrLikeMut + ?Sized> ExactSizeIterator for IterMut<'a, T>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{}
+
+impl<'a, T: 'a + StrLikeMut + ?Sized> FusedIterator for IterMut<'a, T>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{}
+
+/// Draining iterator over `Dynamic`, returned by `Dynamic::drain`.
+///
+/// The drained strings are removed from the vector up front, so they're gone even if the
+/// iterator is dropped early.
+pub struct Drain<T: StrLike + ?Sized, S: Storage<T::Data> = Cow<'static, <T as StrLike>::Data>> {
+    vec: Dynamic<T, S>,
+    idx: usize,
+    back: usize,
+}
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> Drain<T, S> {
+    pub(crate) fn new(vec: Dynamic<T, S>) -> Drain<T, S> {
+        Drain {
+            back: vec.len(),
+            vec: vec,
+            idx: 0,
+        }
+    }
+}
+
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> Iterator for Drain<T, S> {
+    type Item = <T as ToOwned>::Owned;
+    fn next(&mut self) -> Option<<T as ToOwned>::Owned> {
+        if self.idx < self.back {
+            let ret = self.vec[self.idx].to_owned();
+            self.idx += 1;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> DoubleEndedIterator for Drain<T, S> {
+    fn next_back(&mut self) -> Option<<T as ToOwned>::Owned> {
+        if self.idx < self.back {
+            self.back -= 1;
+            Some(self.vec[self.back].to_owned())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> ExactSizeIterator for Drain<T, S> {}
+
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> FusedIterator for Drain<T, S> {}
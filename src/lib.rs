@@ -13,14 +13,30 @@ extern crate void;
 #[cfg_attr(test, macro_use)]
 extern crate quickcheck;
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
 mod array;
+mod frozen;
 mod iter;
+mod prefix;
 mod split;
+mod storage;
 mod strlike;
 mod vec;
 
 pub use array::*;
-pub use iter::Iter;
+pub use frozen::FrozenDynamic;
+pub use iter::{Drain, Iter, IterMut};
+pub use prefix::PrefixSharedDynamic;
+pub use split::{RawError, SplitError};
+pub use storage::{Storage, StorageMut};
 pub use strlike::*;
 pub use vec::*;
 use split::*;
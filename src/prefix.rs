@@ -0,0 +1,221 @@
+use std::fmt;
+use std::mem;
+
+use super::{DataConcat, Dynamic};
+
+/// Vector of strings which stores prefixes shared between consecutive strings only once.
+///
+/// This uses front coding: each string is stored as a suffix, plus an optional reference to a
+/// prefix. When a string is pushed, it shares as long a prefix as possible with the string
+/// immediately before it, and with no other. Unlike a trie, strings which aren't adjacent never
+/// share anything, so this only works well when the strings are sorted, e.g. lists of file paths;
+/// unsorted input may get no sharing at all.
+///
+/// On top of its suffix, every string costs a split index and a 32-bit prefix id, so short shared
+/// prefixes don't save any memory. `heap_size` accounts for this.
+///
+/// Since the strings aren't stored contiguously, reading them back either returns the two parts
+/// separately or allocates a new owned string.
+pub struct PrefixSharedDynamic<T: DataConcat<Data = [u8]> + ?Sized> {
+    prefixes: Dynamic<T>,
+    suffixes: Dynamic<T>,
+    prefix_ids: Vec<u32>,
+}
+
+/// Prefix id for strings which don't share a prefix.
+const NO_PREFIX: u32 = u32::MAX;
+
+impl<T: DataConcat<Data = [u8]> + ?Sized> Default for PrefixSharedDynamic<T> {
+    #[inline]
+    fn default() -> PrefixSharedDynamic<T> {
+        PrefixSharedDynamic::new()
+    }
+}
+
+impl<T: DataConcat<Data = [u8]> + ?Sized> PrefixSharedDynamic<T> {
+    /// Creates an empty `PrefixSharedDynamic`.
+    #[inline]
+    pub fn new() -> PrefixSharedDynamic<T> {
+        PrefixSharedDynamic {
+            prefixes: Dynamic::new(),
+            suffixes: Dynamic::new(),
+            prefix_ids: Vec::new(),
+        }
+    }
+
+    /// Returns the number of strings in the vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.prefix_ids.len()
+    }
+
+    /// Returns `true` iff the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.prefix_ids.is_empty()
+    }
+
+    /// Returns the total length of the data actually stored, including shared prefixes only once.
+    #[inline]
+    pub fn data_len(&self) -> usize {
+        self.prefixes[..].to_data().len() + self.suffixes[..].to_data().len()
+    }
+
+    /// Returns the number of bytes the vector uses on the heap, not counting spare capacity.
+    ///
+    /// Unlike `data_len`, this includes the split indices and prefix ids stored for each string.
+    #[inline]
+    pub fn heap_size(&self) -> usize {
+        self.data_len()
+            + (self.prefixes.len() + self.suffixes.len()) * mem::size_of::<usize>()
+            + self.prefix_ids.len() * mem::size_of::<u32>()
+    }
+
+    /// Adds a string to the end of the vector, sharing as much of a prefix with the previous
+    /// string as possible.
+    pub fn push(&mut self, t: &T) {
+        let data = t.to_data();
+        let last_id = self.prefix_ids.last().cloned().filter(|&id| id != NO_PREFIX);
+
+        let mut shared = match self.len().checked_sub(1).and_then(|idx| self.get_parts(idx)) {
+            Some((prefix, suffix)) => {
+                let last = prefix.to_data().iter().chain(suffix.to_data());
+                data.iter().zip(last).take_while(|&(a, b)| a == b).count()
+            }
+            None => 0,
+        };
+        while shared > 0 && (T::from_data(&data[..shared]).is_err() || T::from_data(&data[shared..]).is_err()) {
+            shared -= 1;
+        }
+
+        let id = match last_id {
+            _ if shared == 0 => NO_PREFIX,
+            Some(id) if self.prefixes[id as usize].to_data().len() == shared => id,
+            _ if self.prefixes.len() < NO_PREFIX as usize => {
+                self.prefixes.push(unsafe { T::from_data_unchecked(&data[..shared]) });
+                (self.prefixes.len() - 1) as u32
+            }
+            _ => {
+                shared = 0;
+                NO_PREFIX
+            }
+        };
+        self.suffixes.push(unsafe { T::from_data_unchecked(&data[shared..]) });
+        self.prefix_ids.push(id);
+    }
+
+    /// Returns the prefix and suffix of the string at the given index, or `None` if it's out of
+    /// bounds.
+    ///
+    /// Strings which don't share a prefix have an empty one.
+    #[inline]
+    pub fn get_parts(&self, index: usize) -> Option<(&T, &T)> {
+        let suffix = self.suffixes.get(index)?;
+        let prefix = match self.prefix_ids[index] {
+            NO_PREFIX => T::default_ref(),
+            id => &self.prefixes[id as usize],
+        };
+        Some((prefix, suffix))
+    }
+
+    /// Reconstructs the string at the given index, or returns `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<<T as ToOwned>::Owned> {
+        let (prefix, suffix) = self.get_parts(index)?;
+        let (prefix, suffix) = (prefix.to_data(), suffix.to_data());
+        let mut data = Vec::with_capacity(prefix.len() + suffix.len());
+        data.extend_from_slice(prefix);
+        data.extend_from_slice(suffix);
+        Some(unsafe { T::from_data_unchecked(&data) }.to_owned())
+    }
+
+    /// Returns an iterator which reconstructs each of the strings in the vector.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = <T as ToOwned>::Owned> + 'a {
+        (0..self.len()).map(move |idx| self.get(idx).unwrap())
+    }
+}
+
+impl<'a, T: DataConcat<Data = [u8]> + ?Sized> Extend<&'a T> for PrefixSharedDynamic<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s);
+        }
+    }
+}
+
+impl<'a, T: DataConcat<Data = [u8]> + ?Sized> ::std::iter::FromIterator<&'a T> for PrefixSharedDynamic<T> {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> PrefixSharedDynamic<T> {
+        let mut vec = PrefixSharedDynamic::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T: DataConcat<Data = [u8]> + fmt::Debug + ?Sized> fmt::Debug for PrefixSharedDynamic<T>
+    where T::Owned: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::PrefixSharedDynamic;
+    use super::super::Dynamic;
+
+    #[test]
+    fn round_trip() {
+        let strings = ["/usr/bin/cat", "/usr/bin/cp", "/usr/lib/libc.so", "/etc/hosts", "", "/usr"];
+        let vec = strings.iter().cloned().collect::<PrefixSharedDynamic<str>>();
+        assert_eq!(vec.len(), strings.len());
+        assert!(vec.iter().eq(strings.iter().map(|s| s.to_string())));
+        assert_eq!(vec.get_parts(1), Some(("/usr/bin/c", "p")));
+        assert_eq!(vec.get_parts(3), Some(("/", "etc/hosts")));
+        assert_eq!(vec.get_parts(4), Some(("", "")));
+        assert_eq!(vec.get(6), None);
+    }
+
+    #[test]
+    fn char_boundary() {
+        let vec = ["中文", "中国"].iter().cloned().collect::<PrefixSharedDynamic<str>>();
+        assert_eq!(vec.get_parts(1), Some(("中", "国")));
+        let vec = ["é", "è"].iter().cloned().collect::<PrefixSharedDynamic<str>>();
+        assert_eq!(vec.get_parts(1), Some(("", "è")));
+        assert_eq!(vec.get(1), Some("è".to_string()));
+    }
+
+    #[test]
+    fn reuses_prefix() {
+        let vec = ["a/b/c1", "a/b/c2", "a/b/c3"].iter().cloned().collect::<PrefixSharedDynamic<str>>();
+        assert_eq!(vec.prefixes.len(), 1);
+        assert_eq!(vec.data_len(), "a/b/c1".len() + "a/b/c".len() + "23".len());
+    }
+
+    #[test]
+    fn paths_memory() {
+        let mut paths = Vec::new();
+        for pkg in 0..20 {
+            for file in 0..50 {
+                paths.push(format!("/usr/share/doc/package-{:02}/examples/file-{:02}.txt", pkg, file));
+            }
+        }
+        let plain = paths.iter().map(String::as_str).collect::<Dynamic<str>>();
+        let shared = paths.iter().map(String::as_str).collect::<PrefixSharedDynamic<str>>();
+        assert!(shared.iter().eq(paths.iter().cloned()));
+
+        let plain_size = plain[..].len() + plain.len() * mem::size_of::<usize>();
+        assert!(shared.heap_size() * 2 < plain_size);
+    }
+
+    #[test]
+    fn unsorted_paths() {
+        let paths = ["/usr/bin/cat", "/etc/hosts", "/usr/bin/cp"];
+        let vec = paths.iter().cloned().collect::<PrefixSharedDynamic<str>>();
+        assert_eq!(vec.get_parts(2), Some(("/", "usr/bin/cp")));
+    }
+}
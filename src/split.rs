@@ -1,5 +1,5 @@
 use std::error::Error;
-use std::ops::{Index, IndexMut, Range, RangeFrom, RangeFull, RangeTo};
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeTo};
 use std::fmt;
 
 /// A split of indices.
@@ -71,6 +71,24 @@ impl<'a> Split<'a> {
         }
     }
 
+    /// Gets the index of the item containing the given position.
+    ///
+    /// Empty items don't contain any positions, so they're skipped. Returns the length of the
+    /// split if the position is past its last index.
+    pub fn element_at(self, pos: usize) -> usize {
+        let mut lo = 0;
+        let mut hi = self.inner.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if unsafe { self.get_idx(mid) } <= pos {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
     /// Checks the validity of the split.
     pub fn check_valid(self, buf_len: usize) -> Result<(), SplitError> {
         for win in self.inner.windows(2) {
@@ -85,6 +103,16 @@ impl<'a> Split<'a> {
         }
         Ok(())
     }
+
+    /// Checks the validity of the split, also requiring that it cover the entire buffer.
+    pub fn check_exact(self, buf_len: usize) -> Result<(), SplitError> {
+        self.check_valid(buf_len)?;
+        let end = self.inner.last().cloned().unwrap_or(0);
+        if end != buf_len {
+            return Err(SplitError::Incomplete(end));
+        }
+        Ok(())
+    }
 }
 
 /// Error when checking validity of split.
@@ -92,6 +120,7 @@ impl<'a> Split<'a> {
 pub enum SplitError {
     NotMonotonic(usize, usize),
     OutOfBounds(usize),
+    Incomplete(usize),
 }
 impl fmt::Display for SplitError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -103,6 +132,9 @@ impl fmt::Display for SplitError {
                        rhs)
             }
             SplitError::OutOfBounds(idx) => write!(f, "split index {} was out of bounds", idx),
+            SplitError::Incomplete(idx) => {
+                write!(f, "split indices ended at {}, before the end of the buffer", idx)
+            }
         }
     }
 }
@@ -111,6 +143,38 @@ impl Error for SplitError {
         match *self {
             SplitError::NotMonotonic(..) => "split indices were not monotonically increasing",
             SplitError::OutOfBounds(..) => "split index was out of bounds",
+            SplitError::Incomplete(..) => "split indices ended before the end of the buffer",
+        }
+    }
+}
+
+/// Error when checking validity of a buffer and its split.
+#[derive(Copy, Clone, Debug)]
+pub enum RawError<E> {
+    /// The split itself was invalid.
+    Split(SplitError),
+
+    /// The string at the given index was invalid.
+    Data(usize, E),
+}
+impl<E> From<SplitError> for RawError<E> {
+    fn from(err: SplitError) -> RawError<E> {
+        RawError::Split(err)
+    }
+}
+impl<E: fmt::Display> fmt::Display for RawError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RawError::Split(ref err) => write!(f, "split indices were invalid: {}", err),
+            RawError::Data(idx, ref err) => write!(f, "string {} was not valid: {}", idx, err),
+        }
+    }
+}
+impl<E: Error> Error for RawError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            RawError::Split(..) => "split indices were invalid",
+            RawError::Data(..) => "string was not valid",
         }
     }
 }
@@ -122,6 +186,38 @@ pub struct SplitRange {
     end: Option<usize>,
 }
 impl SplitRange {
+    /// Creates a range from an arbitrary pair of bounds.
+    ///
+    /// Panics if an inclusive end or exclusive start is `usize::MAX`.
+    pub fn from_bounds<R: RangeBounds<usize>>(range: R) -> SplitRange {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)
+                .unwrap_or_else(|| panic!("start index {} was out of bounds", start)),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(end.checked_add(1)
+                .unwrap_or_else(|| panic!("end index {} was out of bounds", end))),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        SplitRange {
+            start: start,
+            end: end,
+        }
+    }
+
+    /// Resolves this range against a collection of the given length.
+    ///
+    /// Panics if the range is out of bounds or decreasing.
+    pub fn to_range(self, len: usize) -> Range<usize> {
+        let end = self.end.unwrap_or(len);
+        assert!(end <= len, "end index {} was out of bounds", end);
+        assert!(self.start <= end, "start index {} was after end index {}", self.start, end);
+        self.start..end
+    }
+
     /// Index into a buffer with this range.
     pub fn index_into<I: ?Sized + Index<RangeFrom<usize>, Output=I> + Index<Range<usize>, Output=I>>(self, buffer: &I) -> &I {
         if let Some(end) = self.end {
@@ -176,7 +272,8 @@ impl From<RangeFull> for SplitRange {
 
 #[cfg(test)]
 mod tests {
-    use super::Split;
+    use std::ops::Bound;
+    use super::{Split, SplitRange};
 
     fn make_split(v: &mut Vec<usize>) {
         for i in 1..v.len() {
@@ -190,5 +287,24 @@ mod tests {
             make_split(&mut arr);
             Split::new(&arr).check_valid(arr.last().cloned().unwrap_or(0)).is_ok()
         }
+
+        fn element_at_matches_scan(arr: Vec<usize>, pos: usize) -> bool {
+            let mut arr = arr;
+            make_split(&mut arr);
+            let expected = arr.iter().position(|&end| end > pos).unwrap_or(arr.len());
+            Split::new(&arr).element_at(pos) == expected
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn from_bounds_excluded_start_max() {
+        SplitRange::from_bounds((Bound::Excluded(usize::max_value()), Bound::Unbounded));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn from_bounds_included_end_max() {
+        SplitRange::from_bounds(0..=usize::max_value());
     }
 }
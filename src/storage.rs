@@ -0,0 +1,242 @@
+use std::borrow::{Borrow, BorrowMut, Cow};
+
+use len_trait::{Capacity, CapacityMut, Clear, LenMut, SplitAtMut};
+use push_trait::PushBack;
+
+use super::{OwnsStrData, StrData};
+
+/// Buffer backing a `Dynamic`.
+///
+/// By default, a `Dynamic` is backed by a `Cow`, which borrows an empty default value until data
+/// is first added to it.
+///
+/// # Safety
+///
+/// `Dynamic` trusts its storage when converting data back into strings, without checking it
+/// again. Implementors must ensure that `as_data` returns exactly the data pushed to the buffer,
+/// in order, and that `truncate`, `split_off`, `drain_front` and `clear` remove only the data
+/// they describe, leaving the rest untouched.
+pub unsafe trait Storage<D: ?Sized + StrData> {
+    /// Borrows the data in the buffer.
+    fn as_data(&self) -> &D;
+
+    /// Returns the length of data the buffer can hold without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Reserves space for at least `additional` more data.
+    fn reserve(&mut self, additional: usize);
+
+    /// Reserves space for exactly `additional` more data.
+    fn reserve_exact(&mut self, additional: usize);
+
+    /// Shrinks the capacity of the buffer as much as possible.
+    fn shrink_to_fit(&mut self);
+
+    /// Appends data to the end of the buffer.
+    fn push(&mut self, data: &D);
+
+    /// Shortens the buffer to the given length.
+    fn truncate(&mut self, len: usize);
+
+    /// Splits the buffer in two at the given index, returning everything after it.
+    fn split_off(&mut self, at: usize) -> Self;
+
+    /// Removes the first `len` items of data, shifting the rest to the front of the buffer.
+    ///
+    /// By default, this splits off the rest of the data and pushes it back, which keeps the
+    /// existing allocation but makes a temporary copy.
+    fn drain_front(&mut self, len: usize)
+        where Self: Sized
+    {
+        let rest = self.split_off(len);
+        self.clear();
+        self.push(rest.as_data());
+    }
+
+    /// Removes all data from the buffer.
+    fn clear(&mut self);
+}
+
+/// Extension to `Storage`: allows mutating data in place.
+///
+/// # Safety
+///
+/// `as_data_mut` must borrow the same data as `as_data`.
+pub unsafe trait StorageMut<D: ?Sized + StrData + SplitAtMut<usize>>: Storage<D> {
+    /// Mutably borrows the data in the buffer.
+    fn as_data_mut(&mut self) -> &mut D;
+}
+
+unsafe impl<D: ?Sized + StrData + ToOwned + 'static> Storage<D> for Cow<'static, D>
+    where D::Owned: OwnsStrData<D>
+{
+    #[inline]
+    fn as_data(&self) -> &D {
+        self
+    }
+    #[inline]
+    fn capacity(&self) -> usize {
+        match *self {
+            Cow::Borrowed(data) => data.len(),
+            Cow::Owned(ref buf) => buf.capacity(),
+        }
+    }
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.to_mut().reserve(additional)
+    }
+    #[inline]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.to_mut().reserve_exact(additional)
+    }
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.to_mut().shrink_to_fit()
+    }
+    #[inline]
+    fn push(&mut self, data: &D) {
+        self.to_mut().push_back(data);
+    }
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.to_mut().truncate(len)
+    }
+    #[inline]
+    fn split_off(&mut self, at: usize) -> Cow<'static, D> {
+        Cow::Owned(self.to_mut().split_off(at))
+    }
+    #[inline]
+    fn drain_front(&mut self, len: usize) {
+        match *self {
+            Cow::Borrowed(data) => *self = Cow::Borrowed(data.split_at(len).1),
+            Cow::Owned(ref mut buf) => {
+                let rest = buf.split_off(len);
+                buf.clear();
+                buf.push_back(rest.borrow());
+            }
+        }
+    }
+    #[inline]
+    fn clear(&mut self) {
+        self.to_mut().clear()
+    }
+}
+
+unsafe impl<D: ?Sized + StrData + SplitAtMut<usize> + ToOwned + 'static> StorageMut<D> for Cow<'static, D>
+    where D::Owned: OwnsStrData<D> + BorrowMut<D>
+{
+    #[inline]
+    fn as_data_mut(&mut self) -> &mut D {
+        self.to_mut().borrow_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Storage, StorageMut};
+    use super::super::Dynamic;
+
+    /// Fixed-size storage which never allocates.
+    struct Inline {
+        data: [u8; 16],
+        len: usize,
+    }
+
+    impl Default for Inline {
+        fn default() -> Inline {
+            Inline { data: [0; 16], len: 0 }
+        }
+    }
+
+    unsafe impl Storage<[u8]> for Inline {
+        fn as_data(&self) -> &[u8] {
+            &self.data[..self.len]
+        }
+        fn capacity(&self) -> usize {
+            self.data.len()
+        }
+        fn reserve(&mut self, additional: usize) {
+            assert!(self.len + additional <= self.data.len());
+        }
+        fn reserve_exact(&mut self, additional: usize) {
+            self.reserve(additional)
+        }
+        fn shrink_to_fit(&mut self) {}
+        fn push(&mut self, data: &[u8]) {
+            self.data[self.len..self.len + data.len()].copy_from_slice(data);
+            self.len += data.len();
+        }
+        fn truncate(&mut self, len: usize) {
+            if len < self.len {
+                self.len = len;
+            }
+        }
+        fn split_off(&mut self, at: usize) -> Inline {
+            let mut other = Inline::default();
+            other.push(&self.data[at..self.len]);
+            self.len = at;
+            other
+        }
+        fn drain_front(&mut self, len: usize) {
+            self.data.copy_within(len..self.len, 0);
+            self.len -= len;
+        }
+        fn clear(&mut self) {
+            self.len = 0;
+        }
+    }
+
+    unsafe impl StorageMut<[u8]> for Inline {
+        fn as_data_mut(&mut self) -> &mut [u8] {
+            &mut self.data[..self.len]
+        }
+    }
+
+    #[test]
+    fn inline() {
+        let mut vec = <Dynamic<[u8], Inline>>::from_storage(Inline::default());
+        vec.push(b"abc");
+        vec.push(b"");
+        vec.push(b"defg");
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.data_capacity(), 16);
+        assert_eq!(&vec[0], b"abc");
+        assert_eq!(&vec[1], b"");
+        assert_eq!(&vec[2], b"defg");
+        assert_eq!(&vec[..], b"abcdefg");
+
+        vec[2][0] = b'D';
+        assert_eq!(&vec[2], b"Defg");
+
+        assert!(vec.pop());
+        assert!(vec.iter().eq([&b"abc"[..], &b""[..]].iter().cloned()));
+
+        vec.truncate_front(1);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(&vec[0], b"");
+    }
+
+    #[test]
+    fn inline_parts() {
+        let mut storage = Inline::default();
+        storage.push(b"abcdefg");
+        let vec = <Dynamic<[u8], Inline>>::from_storage_parts(storage, vec![3, 3, 7]).unwrap();
+        assert!(vec.iter().eq([&b"abc"[..], &b""[..], &b"defg"[..]].iter().cloned()));
+
+        let storage = vec.into_storage();
+        assert_eq!(storage.as_data(), b"abcdefg");
+        assert!(<Dynamic<str, Inline>>::from_storage_parts(storage, vec![3, 6]).is_err());
+
+        let mut storage = Inline::default();
+        storage.push(b"ab\xff");
+        assert!(<Dynamic<str, Inline>>::from_storage_parts(storage, vec![3]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn inline_overflow() {
+        let mut vec = <Dynamic<[u8], Inline>>::from_storage(Inline::default());
+        vec.push(b"0123456789");
+        vec.push(b"0123456789");
+    }
+}
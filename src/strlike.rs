@@ -1,8 +1,14 @@
 use std::borrow::{Borrow, BorrowMut};
 use std::ffi::{CStr, FromBytesWithNulError};
+#[cfg(unix)]
+use std::ffi::OsStr;
 use std::fmt;
 use std::mem;
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
 use std::ops::{Index, RangeFull};
+#[cfg(unix)]
+use std::path::Path;
 use std::str::{Utf8Error, from_utf8, from_utf8_unchecked};
 
 use bow::ToBox;
@@ -111,6 +117,42 @@ impl StrLike for CStr {
     }
 }
 
+#[cfg(unix)]
+impl StrLike for OsStr {
+    type Data = [u8];
+    type OwnedData = Vec<u8>;
+
+    type ConvError = Void;
+
+    fn to_data(&self) -> &[u8] {
+        self.as_bytes()
+    }
+    fn from_data(data: &[u8]) -> Result<&OsStr, Void> {
+        Ok(OsStr::from_bytes(data))
+    }
+    unsafe fn from_data_unchecked(data: &[u8]) -> &OsStr {
+        OsStr::from_bytes(data)
+    }
+}
+
+#[cfg(unix)]
+impl StrLike for Path {
+    type Data = [u8];
+    type OwnedData = Vec<u8>;
+
+    type ConvError = Void;
+
+    fn to_data(&self) -> &[u8] {
+        self.as_os_str().as_bytes()
+    }
+    fn from_data(data: &[u8]) -> Result<&Path, Void> {
+        Ok(Path::new(OsStr::from_bytes(data)))
+    }
+    unsafe fn from_data_unchecked(data: &[u8]) -> &Path {
+        Path::new(OsStr::from_bytes(data))
+    }
+}
+
 unsafe impl DataConcat for str {}
 unsafe impl<T: 'static + Copy> DataConcat for [T] {}
 
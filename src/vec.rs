@@ -1,35 +1,34 @@
 use std::borrow::{Borrow, BorrowMut};
-use std::cmp::Ordering;
-use std::ffi::CStr;
+use std::cmp::{self, Ordering};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString, NulError};
 use std::borrow::Cow;
-use std::ops::{Index, IndexMut, Range, RangeTo, RangeFrom, RangeFull};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut, Index, IndexMut, Range, RangeBounds, RangeTo, RangeFrom, RangeFull};
 use std::fmt;
+use std::io::{self, Read};
+use std::hash::Hash;
 use std::iter::FromIterator;
 
+use bow::ToBox;
 use extra_default::DefaultRef;
-use len_trait::{Capacity, CapacityMut, WithCapacity, Len, LenMut, Clear, SplitAtMut};
+use len_trait::{WithCapacity, Len, SplitAtMut};
 use push_trait::PushBack;
 
-use super::{Split, StrLike, Iter, DataConcat, StrLikeMut};
+use super::{Drain, FrozenDynamic, RawError, Split, SplitRange, Storage, StorageMut, StrLike, Iter, IterMut, DataConcat, StrLikeMut};
 
 /// Vec of immutable strings stored on the heap in the same buffer.
 ///
 /// Slicing ranges of the vector yields the strings in the range concatenated together.
-pub struct Dynamic<T: StrLike + ?Sized> {
-    buffer: Cow<'static, T::Data>,
+///
+/// The buffer is a `Cow` by default, but any other `Storage` may be used instead.
+pub struct Dynamic<T: StrLike + ?Sized, S: Storage<T::Data> = Cow<'static, <T as StrLike>::Data>> {
+    buffer: S,
     split: Vec<usize>,
+    marker: PhantomData<fn() -> Box<T>>,
 }
 
-unsafe impl<T: StrLike + ?Sized> Send for Dynamic<T>
-    where &'static T::Data: Send,
-          T::OwnedData: Send,
-{}
-
-unsafe impl<T: StrLike + ?Sized> Sync for Dynamic<T>
-    where &'static T::Data: Sync,
-          T::OwnedData: Sync,
-{}
-
 impl<T: StrLike + ?Sized> Default for Dynamic<T> {
     fn default() -> Dynamic<T> {
         Dynamic::new()
@@ -40,9 +39,7 @@ impl<'a, T: StrLike + ?Sized> FromIterator<&'a T> for Dynamic<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Dynamic<T> {
         let mut v = Self::new();
-        for item in iter {
-            v.push(item);
-        }
+        v.extend(iter);
         v
     }
 }
@@ -50,29 +47,66 @@ impl<'a, T: StrLike + ?Sized> FromIterator<&'a &'a T> for Dynamic<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = &'a &'a T>>(iter: I) -> Dynamic<T> {
         let mut v = Self::new();
-        for &item in iter {
-            v.push(item);
-        }
+        v.extend(iter);
         v
     }
 }
-impl<'a, T: StrLike + ?Sized> Extend<&'a &'a T> for Dynamic<T> {
+/// Collects owned strings into a `Dynamic`, reserving space for the lower bound of strings.
+fn from_owned_iter<T: StrLike + ?Sized, O: Borrow<T>, I: IntoIterator<Item = O>>(iter: I) -> Dynamic<T> {
+    let iter = iter.into_iter();
+    let mut v = Dynamic::new();
+    v.split.reserve(iter.size_hint().0);
+    for item in iter {
+        v.push(item.borrow());
+    }
+    v
+}
+impl FromIterator<String> for Dynamic<str> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Dynamic<str> {
+        from_owned_iter(iter)
+    }
+}
+impl FromIterator<CString> for Dynamic<CStr> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = CString>>(iter: I) -> Dynamic<CStr> {
+        from_owned_iter(iter)
+    }
+}
+impl<T: 'static + Copy> FromIterator<Vec<T>> for Dynamic<[T]> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Dynamic<[T]> {
+        from_owned_iter(iter)
+    }
+}
+impl<'a, T: StrLike + ?Sized, S: Storage<T::Data>> Extend<&'a &'a T> for Dynamic<T, S> {
     #[inline]
     fn extend<I: IntoIterator<Item = &'a &'a T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        // the data length isn't known up front, and reserving it would allocate a borrowed buffer
+        self.split.reserve(iter.size_hint().0);
         for &item in iter {
             self.push(item);
         }
     }
 }
-impl<'a, T: StrLike + ?Sized> Extend<&'a T> for Dynamic<T> {
+impl<'a, T: StrLike + ?Sized, S: Storage<T::Data>> Extend<&'a T> for Dynamic<T, S> {
     #[inline]
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.split.reserve(iter.size_hint().0);
         for item in iter {
             self.push(item);
         }
     }
 }
-impl<'a, T: StrLike + ?Sized> IntoIterator for &'a Dynamic<T> {
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> From<Dynamic<T, S>> for Vec<<T as ToOwned>::Owned> {
+    #[inline]
+    fn from(vec: Dynamic<T, S>) -> Vec<<T as ToOwned>::Owned> {
+        vec.to_vec()
+    }
+}
+impl<'a, T: StrLike + ?Sized, S: Storage<T::Data>> IntoIterator for &'a Dynamic<T, S> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
 
@@ -89,6 +123,7 @@ impl<T: StrLike + ?Sized> Dynamic<T> {
         Dynamic {
             buffer: Cow::Borrowed(DefaultRef::default_ref()),
             split: Vec::new(),
+            marker: PhantomData,
         }
     }
 
@@ -99,12 +134,123 @@ impl<T: StrLike + ?Sized> Dynamic<T> {
     /// allocate.
     #[inline]
     pub fn with_capacities(num: usize, data: usize) -> Dynamic<T> {
+        let buffer = if data == 0 {
+            Cow::Borrowed(DefaultRef::default_ref())
+        } else {
+            Cow::Owned(WithCapacity::with_capacity(data))
+        };
         Dynamic {
-            buffer: Cow::Owned(WithCapacity::with_capacity(data)),
+            buffer,
             split: Vec::with_capacity(num),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a `Dynamic` from its raw parts: a buffer and a list of split indices.
+    ///
+    /// An owned buffer, like a `Vec<u8>`, is moved into the vector without copying it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the split indices don't cover the whole buffer in increasing order, or if any of
+    /// the strings they produce are invalid.
+    pub fn from_raw<D: Into<Cow<'static, T::Data>>>(buffer: D, split: Vec<usize>) -> Dynamic<T> {
+        let buffer = buffer.into();
+        Dynamic::<T>::check_parts(&buffer, &split).unwrap_or_else(|e| panic!("{}", e));
+        Dynamic { buffer, split, marker: PhantomData }
+    }
+
+    /// Creates a `Dynamic` from its raw parts (unsafe version).
+    #[inline]
+    pub unsafe fn from_raw_unchecked<D: Into<Cow<'static, T::Data>>>(buffer: D, split: Vec<usize>) -> Dynamic<T> {
+        Dynamic { buffer: buffer.into(), split, marker: PhantomData }
+    }
+
+    /// Creates a `Dynamic` containing the given strings with `sep` inserted between each of them.
+    ///
+    /// For example, joining `["a", "b"]` with `"-"` gives `["a", "-", "b"]`.
+    pub fn from_joined(parts: &[&T], sep: &T) -> Dynamic<T>
+        where T: DataConcat
+    {
+        if parts.is_empty() {
+            return Dynamic::new();
+        }
+        let seps = parts.len() - 1;
+        let data = parts.iter().map(|part| part.to_data().len()).sum::<usize>() + seps * sep.to_data().len();
+        let mut vec = Dynamic::with_capacities(parts.len() + seps, data);
+        vec.push(parts[0]);
+        for part in &parts[1..] {
+            vec.push(sep);
+            vec.push(part);
+        }
+        vec
+    }
+
+    /// Returns `true` iff the buffer is still borrowed, i.e. hasn't been allocated yet.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        match self.buffer {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(_) => false,
+        }
+    }
+
+    /// Clears the vector and frees its buffer, going back to borrowing an empty default.
+    ///
+    /// Unlike `clear`, this doesn't keep any capacity around for later strings.
+    pub fn clear_and_release(&mut self) {
+        self.buffer = Cow::Borrowed(DefaultRef::default_ref());
+        self.split = Vec::new();
+    }
+
+    /// Converts the vector into a `FrozenDynamic`, discarding any spare capacity.
+    ///
+    /// This is only available for vectors backed by the default `Cow` storage.
+    pub fn into_frozen(self) -> FrozenDynamic<T> {
+        let buffer = match self.buffer {
+            Cow::Borrowed(data) => data.to_box(),
+            Cow::Owned(data) => data.into(),
+        };
+        FrozenDynamic::from_parts(buffer, self.split.into_boxed_slice())
+    }
+}
+
+impl<T: StrLike + ?Sized, S: Storage<T::Data>> Dynamic<T, S> {
+    /// Creates an empty `Dynamic` backed by the given storage.
+    ///
+    /// Any data already in the storage is cleared; use `from_storage_parts` to keep it.
+    #[inline]
+    pub fn from_storage(storage: S) -> Dynamic<T, S> {
+        let mut buffer = storage;
+        buffer.clear();
+        Dynamic {
+            buffer,
+            split: Vec::new(),
+            marker: PhantomData,
         }
     }
 
+    /// Creates a `Dynamic` from storage which already holds data, and the split indices which
+    /// partition it into strings.
+    ///
+    /// Fails if the split indices don't cover the whole buffer in increasing order, or if any of
+    /// the strings they produce are invalid.
+    pub fn from_storage_parts(storage: S, split: Vec<usize>) -> Result<Dynamic<T, S>, RawError<T::ConvError>> {
+        Dynamic::<T, S>::check_parts(storage.as_data(), &split)?;
+        Ok(Dynamic {
+            buffer: storage,
+            split,
+            marker: PhantomData,
+        })
+    }
+
+    /// Converts the vector back into its storage, which holds the data of all its strings in
+    /// order.
+    #[inline]
+    pub fn into_storage(self) -> S {
+        self.buffer
+    }
+
     /// Returns the number of strings this vector can hold without reallocating.
     #[inline]
     pub fn num_capacity(&self) -> usize {
@@ -114,51 +260,145 @@ impl<T: StrLike + ?Sized> Dynamic<T> {
     /// Returns the total length of strings this vector can hold without reallocating.
     #[inline]
     pub fn data_capacity(&self) -> usize {
-        match self.buffer {
-            Cow::Borrowed(slice) => slice.len(),
-            Cow::Owned(ref buf) => buf.capacity(),
-        }
+        self.buffer.capacity()
+    }
+
+    /// Returns the total length of the strings in the vector.
+    #[inline]
+    pub fn data_len(&self) -> usize {
+        self.split.last().cloned().unwrap_or(0)
+    }
+
+    /// Returns the number of strings that can be pushed without reallocating.
+    #[inline]
+    pub fn element_capacity_remaining(&self) -> usize {
+        self.num_capacity() - self.len()
+    }
+
+    /// Returns the total length of strings that can be pushed without reallocating.
+    #[inline]
+    pub fn byte_capacity_remaining(&self) -> usize {
+        self.data_capacity() - self.data_len()
     }
 
     /// Reserves capacity for at least `additional` more strings totalling to `bytes` more
     /// bytes.
     #[inline]
     pub fn reserve(&mut self, additional: usize, bytes: usize) {
-        self.buffer.to_mut().reserve(bytes);
+        self.buffer.reserve(bytes);
         self.split.reserve(additional);
     }
 
+    /// Reserves enough capacity to append all of the strings in `other` without reallocating.
+    #[inline]
+    pub fn reserve_for_dynamic<S2: Storage<T::Data>>(&mut self, other: &Dynamic<T, S2>) {
+        self.reserve(other.len(), other.split.last().cloned().unwrap_or(0));
+    }
+
     /// Similar to `reserve`, calling `reserve_exact` on the inner `String` and `Vec`.
     #[inline]
     pub fn reserve_exact(&mut self, additional: usize, bytes: usize) {
-        self.buffer.to_mut().reserve_exact(bytes);
+        self.buffer.reserve_exact(bytes);
         self.split.reserve_exact(additional);
     }
 
     /// See: `Vec::shrink_to_fit`.
     #[inline]
     pub fn shrink_to_fit(&mut self) {
-        self.buffer.to_mut().shrink_to_fit();
+        self.buffer.shrink_to_fit();
         self.split.shrink_to_fit();
     }
 
     /// Shortens the buffer, keeping the first `len` slices and dropping the rest.
+    ///
+    /// If `len` is at least the length of the vector, this does nothing.
     #[inline]
     pub fn truncate(&mut self, len: usize) {
-        self.buffer.to_mut().truncate(self.split[len]);
-        self.split.truncate(len);
+        if len < self.len() {
+            let end = self.data_start(len);
+            self.buffer.truncate(end);
+            self.split.truncate(len);
+        }
+    }
+
+    /// Removes the first `remove` strings, shifting the rest to the front of the buffer.
+    ///
+    /// If `remove` is at least the length of the vector, the vector is cleared.
+    pub fn truncate_front(&mut self, remove: usize) {
+        if remove >= self.len() {
+            return self.clear();
+        }
+        if remove == 0 {
+            return;
+        }
+
+        let start = self.split[remove - 1];
+        self.buffer.drain_front(start);
+        self.split.drain(..remove);
+        for idx in &mut self.split {
+            *idx -= start;
+        }
+    }
+
+    /// Removes strings from the front of the vector until the rest take up at most `max_bytes`.
+    ///
+    /// This keeps the most recently pushed strings within a memory budget. Strings are removed
+    /// whole, so the rest may take up less than `max_bytes`.
+    pub fn retain_within_bytes(&mut self, max_bytes: usize) {
+        let excess = self.data_len().saturating_sub(max_bytes);
+        if excess > 0 {
+            let remove = Split::new(&self.split).element_at(excess - 1) + 1;
+            self.truncate_front(remove);
+        }
+    }
+
+    /// Keeps only the strings in the given range, dropping everything before and after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or decreasing.
+    pub fn retain_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let range = SplitRange::from_bounds(range).to_range(self.len());
+        let end = self.data_start(range.end);
+        self.buffer.truncate(end);
+        self.split.truncate(range.end);
+        self.truncate_front(range.start);
+    }
+
+    /// Removes the strings in the given range, returning an iterator over them.
+    ///
+    /// The strings are removed and the rest of the buffer is shifted down immediately, even if
+    /// the iterator is dropped before it's used up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or decreasing.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<T, S> {
+        let range = SplitRange::from_bounds(range).to_range(self.len());
+        let start = self.data_start(range.start);
+        let end = self.data_start(range.end);
+
+        let mut buffer = self.buffer.split_off(start);
+        let rest = buffer.split_off(end - start);
+        self.buffer.push(rest.as_data());
+
+        let split = self.split.drain(range.clone()).map(|idx| idx - start).collect();
+        for idx in &mut self.split[range.start..] {
+            *idx -= end - start;
+        }
+        Drain::new(Dynamic { buffer, split, marker: PhantomData })
     }
 
     /// Moves all of the elements of `other` into `self`, leaving `other` empty.
-    pub fn append(&mut self, other: &mut Dynamic<T>) {
+    pub fn append(&mut self, other: &mut Dynamic<T, S>) {
         if let Some(&idx) = self.split.last() {
             for other_idx in &mut other.split {
                 *other_idx += idx;
             }
         }
 
-        self.buffer.to_mut().push_back(other.buffer.borrow());
-        other.buffer.to_mut().clear();
+        self.buffer.push(other.buffer.as_data());
+        other.buffer.clear();
 
         self.split.append(&mut other.split);
     }
@@ -176,26 +416,26 @@ impl<T: StrLike + ?Sized> Dynamic<T> {
     }
 
     /// Splits the collection into two at the given index.
-    pub fn split_off(&mut self, at: usize) -> Dynamic<T> {
+    pub fn split_off(&mut self, at: usize) -> Dynamic<T, S> {
         let mut new_split = self.split.split_off(at);
-        if let Some(&split_idx) = self.split.last() {
-            for idx in &mut new_split {
-                *idx -= split_idx;
-            }
+        let split_idx = self.split.last().cloned().unwrap_or(0);
+        for idx in &mut new_split {
+            *idx -= split_idx;
         }
 
-        let new_buffer = self.buffer.to_mut().split_off(at);
+        let new_buffer = self.buffer.split_off(split_idx);
 
         Dynamic {
-            buffer: Cow::Owned(new_buffer),
+            buffer: new_buffer,
             split: new_split,
+            marker: PhantomData,
         }
     }
 
     /// Clears the vector, removing all strings.
     #[inline]
     pub fn clear(&mut self) {
-        self.buffer.to_mut().clear();
+        self.buffer.clear();
         self.split.clear();
     }
 
@@ -203,16 +443,46 @@ impl<T: StrLike + ?Sized> Dynamic<T> {
     pub fn push(&mut self, t: &T) {
         let t = t.to_data();
         let split = self.split.last().cloned().unwrap_or(0) + t.len();
-        self.buffer.to_mut().push_back(t);
+        self.buffer.push(t);
         self.split.push(split);
     }
 
+    /// Adds a string to the end of the vec, preceded by `sep` unless the vec is empty.
+    pub fn push_with_separator(&mut self, sep: &T, t: &T) {
+        if !self.is_empty() {
+            self.push(sep);
+        }
+        self.push(t);
+    }
+
+    /// Removes a string from the front of the vec and allocates it onto a new buffer.
+    ///
+    /// This shifts the entire buffer, so it takes O(n) time.
+    pub fn pop_front(&mut self) -> Option<<T as ToOwned>::Owned> {
+        let ret = self.get(0)?.to_owned();
+        self.truncate_front(1);
+        Some(ret)
+    }
+
+    /// Adds a string to the end of the vec, then removes and returns the string at the front if
+    /// the vec holds more than `max` strings.
+    ///
+    /// Repeatedly calling this with the same `max` makes the vec act as a fixed-size FIFO.
+    pub fn push_capped(&mut self, t: &T, max: usize) -> Option<<T as ToOwned>::Owned> {
+        self.push(t);
+        if self.len() > max {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
     /// Removes a string from the end of the vec and discards it.
     pub fn pop(&mut self) -> bool {
         match self.split.pop() {
             None => false,
             Some(idx) => {
-                self.buffer.to_mut().truncate(idx);
+                self.buffer.truncate(idx);
                 true
             }
         }
@@ -232,355 +502,2975 @@ impl<T: StrLike + ?Sized> Dynamic<T> {
 
         let idx = self.split.last().cloned().unwrap_or(0);
 
-        let ret = unsafe { T::from_data_unchecked(hack(&self.buffer, idx)).to_owned() };
-        self.buffer.to_mut().truncate(idx);
+        let ret = unsafe { T::from_data_unchecked(hack(self.buffer.as_data(), idx)).to_owned() };
+        self.buffer.truncate(idx);
         Some(ret)
     }
 
-    /// Returns an iterator over the strings in the vector.
-    #[inline]
-    pub fn iter(&self) -> Iter<T> {
-        Iter::new(&*self.buffer, &*self.split)
+    /// Inserts a string at the given index, shifting all strings after it up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the vector.
+    pub fn insert(&mut self, index: usize, t: &T) {
+        assert!(index <= self.len(), "index {} was out of bounds", index);
+        let t = t.to_data();
+        let start = self.data_start(index);
+        let tail = self.buffer.split_off(start);
+        self.buffer.push(t);
+        self.buffer.push(tail.as_data());
+        for idx in &mut self.split[index..] {
+            *idx += t.len();
+        }
+        self.split.insert(index, start + t.len());
     }
-}
 
-impl<T: ?Sized + StrLike> Index<usize> for Dynamic<T> {
-    type Output = T;
-    #[inline]
-    fn index(&self, index: usize) -> &T {
-        assert_ne!(index, self.len());
-        unsafe {
-            let split = Split::new(&*self.split);
-            T::from_data_unchecked(split.get(index).index_into(&*self.buffer))
+    /// Removes the string at the given index, shifting all strings after it down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> <T as ToOwned>::Owned {
+        let ret = self.get(index).unwrap_or_else(|| panic!("index {} was out of bounds", index)).to_owned();
+
+        let start = self.data_start(index);
+        let end = self.split.remove(index);
+        let tail = self.buffer.split_off(end);
+        self.buffer.truncate(start);
+        self.buffer.push(tail.as_data());
+        for idx in &mut self.split[index..] {
+            *idx -= end - start;
         }
+        ret
     }
-}
 
-impl<T: ?Sized + StrLike + StrLikeMut> IndexMut<usize> for Dynamic<T>
-    where T::Data: SplitAtMut<usize>,
-          T::OwnedData: BorrowMut<T::Data>
-{
-    #[inline]
-    fn index_mut(&mut self, index: usize) -> &mut T {
-        assert_ne!(index, self.len());
-        unsafe {
-            let idx = Split::new(&*self.split).get(index);
-            T::from_data_mut_unchecked(idx.index_into_mut(self.buffer.to_mut().borrow_mut()))
+    /// Swaps the strings at indices `a` and `b`.
+    ///
+    /// Since the strings can have different lengths, this rebuilds the buffer from the first of
+    /// the two strings onward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len(), "index {} was out of bounds", a);
+        assert!(b < self.len(), "index {} was out of bounds", b);
+        let (a, b) = if a < b { (a, b) } else if b < a { (b, a) } else { return };
+
+        let a_start = self.data_start(a);
+        let (a_end, b_start, b_end) = (self.split[a], self.data_start(b), self.split[b]);
+        {
+            let tail = self.buffer.split_off(a_start);
+            let tail = tail.as_data();
+            let offset = |range: Range<usize>| SplitRange::from(range.start - a_start..range.end - a_start);
+            self.buffer.push(offset(b_start..b_end).index_into(tail));
+            self.buffer.push(offset(a_end..b_start).index_into(tail));
+            self.buffer.push(offset(a_start..a_end).index_into(tail));
+            self.buffer.push(SplitRange::from(b_end - a_start..).index_into(tail));
         }
-    }
-}
 
-impl<T: ?Sized + DataConcat> Index<Range<usize>> for Dynamic<T> {
-    type Output = T;
-    #[inline]
-    fn index(&self, range: Range<usize>) -> &T {
-        unsafe {
-            let split = Split::new(&*self.split);
-            T::from_data_unchecked(split.get_slice(range.into()).index_into(&*self.buffer))
+        let (a_len, b_len) = (a_end - a_start, b_end - b_start);
+        for idx in &mut self.split[a..b] {
+            *idx = *idx + b_len - a_len;
         }
     }
-}
 
-impl<T: ?Sized + DataConcat> Index<RangeFrom<usize>> for Dynamic<T> {
-    type Output = T;
-    #[inline]
-    fn index(&self, range: RangeFrom<usize>) -> &T {
-        unsafe {
-            let split = Split::new(&*self.split);
-            T::from_data_unchecked(split.get_slice(range.into()).index_into(&*self.buffer))
+    /// Removes the string at the given index, replacing it with the last string.
+    ///
+    /// Since the strings can have different lengths, this still shifts all of the data after
+    /// `index`, but only once, and doesn't change the order of the strings in between. Removing
+    /// the last string is as cheap as `pop_off`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> <T as ToOwned>::Owned {
+        assert!(index < self.len(), "index {} was out of bounds", index);
+        let last = self.pop_off().unwrap();
+        if index == self.len() {
+            return last;
+        }
+
+        let ret = self[index].to_owned();
+        let last = last.borrow().to_data();
+        let start = self.data_start(index);
+        let end = self.split[index];
+        let tail = self.buffer.split_off(end);
+        self.buffer.truncate(start);
+        self.buffer.push(last);
+        self.buffer.push(tail.as_data());
+        for idx in &mut self.split[index..] {
+            *idx = *idx + last.len() - (end - start);
         }
+        ret
     }
-}
 
-impl<T: ?Sized + DataConcat> Index<RangeTo<usize>> for Dynamic<T> {
-    type Output = T;
+    /// Returns the string at the given index, or `None` if it's out of bounds.
     #[inline]
-    fn index(&self, range: RangeTo<usize>) -> &T {
-        unsafe {
-            let split = Split::new(&*self.split);
-            T::from_data_unchecked(split.get_slice(range.into()).index_into(&*self.buffer))
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            unsafe {
+                let split = Split::new(&*self.split);
+                Some(T::from_data_unchecked(split.get(index).index_into(self.buffer.as_data())))
+            }
+        } else {
+            None
         }
     }
-}
 
-impl<T: ?Sized + DataConcat> Index<RangeFull> for Dynamic<T> {
-    type Output = T;
+    /// Returns the first string in the vector, or `None` if it's empty.
     #[inline]
-    fn index(&self, _: RangeFull) -> &T {
-        unsafe {
-            T::from_data_unchecked(&*self.buffer)
-        }
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
     }
-}
 
-impl<T: ?Sized + StrLike> Clone for Dynamic<T>
-    where Cow<'static, T::Data>: Clone
-{
-    fn clone(&self) -> Dynamic<T> {
-        Dynamic {
-            buffer: self.buffer.clone(),
-            split: self.split.clone(),
-        }
+    /// Returns the last string in the vector, or `None` if it's empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        let end = *self.split.last()?;
+        let start = self.data_start(self.len() - 1);
+        unsafe { Some(T::from_data_unchecked(SplitRange::from(start..end).index_into(self.buffer.as_data()))) }
     }
-    fn clone_from(&mut self, source: &Dynamic<T>) {
-        self.buffer.clone_from(&source.buffer);
-        self.split.clone_from(&source.split);
+
+    /// Returns an iterator over the strings in the vector.
+    #[inline]
+    pub fn iter(&self) -> Iter<T> {
+        Iter::new(self.buffer.as_data(), &*self.split)
     }
-}
 
-impl<T: ?Sized + StrLike> ::std::hash::Hash for Dynamic<T>
-    where T::Data: ::std::hash::Hash
-{
-    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
-        self.buffer.hash(state);
-        self.split.hash(state);
+    /// Copies the strings in the given range into a new, independent vector.
+    ///
+    /// The new vector always uses the default `Cow` storage, whatever storage this one uses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or decreasing.
+    pub fn sub_vec<R: RangeBounds<usize>>(&self, range: R) -> Dynamic<T> {
+        let range = SplitRange::from_bounds(range).to_range(self.len());
+        let start = self.data_start(range.start);
+        let end = self.data_start(range.end);
+
+        let mut vec = <Dynamic<T>>::with_capacities(range.len(), end - start);
+        vec.buffer.push(SplitRange::from(start..end).index_into(self.buffer.as_data()));
+        vec.split.extend(self.split[range].iter().map(|idx| idx - start));
+        vec
     }
-}
 
-impl<T: ?Sized + StrLike + PartialEq> PartialEq for Dynamic<T> {
-    fn eq(&self, rhs: &Dynamic<T>) -> bool {
-        self.iter().eq(rhs.iter())
+    /// Returns an iterator over owned vectors of `size` strings each, with the last one holding
+    /// any that remain.
+    ///
+    /// The new vectors always use the default `Cow` storage, whatever storage this one uses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunk_vecs<'a>(&'a self, size: usize) -> impl Iterator<Item = Dynamic<T>> + 'a {
+        assert!(size != 0, "chunk size must be nonzero");
+        let len = self.len();
+        (0..len).step_by(size).map(move |start| {
+            let end = cmp::min(start + size, len);
+            let mut vec = Dynamic::with_capacities(end - start, self.split[end - 1] - self.data_start(start));
+            vec.extend(self.iter().skip(start).take(end - start));
+            vec
+        })
     }
-}
 
-impl<'a, T: ?Sized + StrLike + PartialEq> PartialEq<&'a [&'a T]> for Dynamic<T> {
-    fn eq(&self, rhs: &&'a [&'a T]) -> bool {
-        self.iter().eq(rhs.iter().cloned())
+    /// Returns an iterator over the strings in the vector, from back to front.
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.iter().rev()
     }
-}
 
-impl<'a, T: ?Sized + StrLike + PartialEq> PartialEq<Vec<&'a T>> for Dynamic<T> {
-    fn eq(&self, rhs: &Vec<&'a T>) -> bool {
-        self.iter().eq(rhs.iter().cloned())
+    /// Finds the first index at which two vectors differ.
+    ///
+    /// Returns the index along with the strings at that index in each vector, where a string is
+    /// `None` if its vector has already ended. Returns `None` if the vectors are equal.
+    pub fn first_difference<'a>(&'a self, other: &'a Dynamic<T, S>) -> Option<(usize, Option<&'a T>, Option<&'a T>)>
+        where T: PartialEq
+    {
+        let mut lhs = self.iter();
+        let mut rhs = other.iter();
+        let mut idx = 0;
+        loop {
+            match (lhs.next(), rhs.next()) {
+                (None, None) => return None,
+                (Some(l), Some(r)) if l == r => idx += 1,
+                (l, r) => return Some((idx, l, r)),
+            }
+        }
     }
-}
 
-/*
-impl<T: ?Sized + StrLike + PartialEq> PartialEq<Vec<T::Owned>> for Dynamic<T> {
-    fn eq(&self, rhs: &Vec<T::Owned>) -> bool {
-        self.iter().eq(rhs.iter().map(|s| &*s))
+    /// Keeps only the strings for which `f` returns `true`, preserving their order.
+    ///
+    /// Strings before the first removed one stay where they are. The data after it is split off
+    /// once, and the kept strings are copied back into the same buffer in a single pass, keeping
+    /// its capacity.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut tail: Option<(usize, S)> = None;
+        let mut start = 0;
+        let mut write = 0;
+        let mut kept = 0;
+        for idx in 0..self.split.len() {
+            let end = self.split[idx];
+            let keep = {
+                let data = match tail {
+                    Some((offset, ref tail)) => {
+                        SplitRange::from(start - offset..end - offset).index_into(tail.as_data())
+                    }
+                    None => SplitRange::from(start..end).index_into(self.buffer.as_data()),
+                };
+                f(unsafe { T::from_data_unchecked(data) })
+            };
+            if keep {
+                if let Some((offset, ref tail)) = tail {
+                    self.buffer.push(SplitRange::from(start - offset..end - offset).index_into(tail.as_data()));
+                }
+                write += end - start;
+                self.split[kept] = write;
+                kept += 1;
+            } else if tail.is_none() {
+                tail = Some((start, self.buffer.split_off(start)));
+            }
+            start = end;
+        }
+        self.split.truncate(kept);
     }
-}
-*/
 
-impl<T: ?Sized + StrLike + Eq> Eq for Dynamic<T> {}
+    /// Sorts the strings in the vector.
+    ///
+    /// This sort is stable. The strings are compared in place, and the buffer is rebuilt once
+    /// their order is known.
+    #[inline]
+    pub fn sort(&mut self)
+        where T: Ord
+    {
+        self.sort_by(Ord::cmp)
+    }
 
-impl<T: ?Sized + StrLike + PartialOrd> PartialOrd for Dynamic<T> {
-    fn partial_cmp(&self, rhs: &Dynamic<T>) -> Option<Ordering> {
-        self.iter().partial_cmp(rhs.iter())
+    /// Sorts the strings in the vector with the given comparison function.
+    ///
+    /// This sort is stable. The strings are compared in place, and the buffer is rebuilt once
+    /// their order is known.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut f: F) {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_by(|&a, &b| f(&self[a], &self[b]));
+        self.permute(&order);
     }
-}
 
-impl<'a, T: ?Sized + StrLike + PartialOrd> PartialOrd<&'a [&'a T]> for Dynamic<T> {
-    fn partial_cmp(&self, rhs: &&'a [&'a T]) -> Option<Ordering> {
-        self.iter().partial_cmp(rhs.iter().cloned())
+    /// Reverses the order of the strings in the vector.
+    pub fn reverse(&mut self) {
+        let order: Vec<usize> = (0..self.len()).rev().collect();
+        self.permute(&order);
     }
-}
 
-impl<'a, T: ?Sized + StrLike + PartialOrd> PartialOrd<Vec<&'a T>> for Dynamic<T> {
-    fn partial_cmp(&self, rhs: &Vec<&'a T>) -> Option<Ordering> {
-        self.iter().partial_cmp(rhs.iter().cloned())
+    /// Returns `true` iff the vector contains a string equal to `needle`.
+    #[inline]
+    pub fn contains(&self, needle: &T) -> bool
+        where T: PartialEq
+    {
+        self.iter().any(|s| s == needle)
     }
-}
 
-/*
-impl<T: ?Sized + StrLike + PartialOrd> PartialOrd<Vec<T::Owned>> for Dynamic<T> {
-    fn partial_cmp(&self, rhs: &Vec<T::Owned>) -> Option<Ordering> {
-        self.iter().partial_cmp(rhs.iter().map(|s| &*s))
+    /// Searches a sorted vector for the given string.
+    ///
+    /// Like `slice::binary_search`, returns `Ok` with the index of a matching string if one is
+    /// found, or `Err` with the index where the string could be inserted to keep the vector
+    /// sorted. If the vector isn't sorted, the result is meaningless.
+    pub fn binary_search(&self, needle: &T) -> Result<usize, usize>
+        where T: Ord
+    {
+        let mut lo = 0;
+        let mut hi = self.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self[mid].cmp(needle) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
     }
-}
-*/
 
-impl<T: ?Sized + StrLike + Ord> Ord for Dynamic<T> {
-    fn cmp(&self, rhs: &Dynamic<T>) -> Ordering {
-        self.iter().cmp(rhs.iter())
+    /// Returns the index of the first string matching the given predicate, like
+    /// `Iterator::position`.
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<usize> {
+        let split = Split::new(&*self.split);
+        (0..split.len()).find(|&idx| {
+            f(unsafe { T::from_data_unchecked(split.get(idx).index_into(self.buffer.as_data())) })
+        })
     }
-}
 
-impl<T: ?Sized + StrLike + fmt::Debug> fmt::Debug for Dynamic<T> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_list()
-            .entries(self.iter())
-            .finish()
+    /// Returns the index of the last string matching the given predicate, like
+    /// `Iterator::rposition`.
+    pub fn rposition<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<usize> {
+        let split = Split::new(&*self.split);
+        (0..split.len()).rev().find(|&idx| {
+            f(unsafe { T::from_data_unchecked(split.get(idx).index_into(self.buffer.as_data())) })
+        })
     }
-}
 
-#[cfg(feature = "quickcheck")]
-impl<T: ?Sized + StrLike> quickcheck::Arbitrary for Dynamic<T>
-    where T::Owned: quickcheck::Arbitrary,
-          Dynamic<T>: Send + Sync
-{
-    fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> Dynamic<T> {
-        let mut vec = Dynamic::new();
+    /// Returns the indices of all strings matching the given predicate.
+    pub fn indices_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Vec<usize> {
+        self.iter().enumerate().filter(|&(_, s)| pred(s)).map(|(idx, _)| idx).collect()
+    }
 
-        let size = g.size();
-        let size = g.gen_range(0, size);
-        for _ in 0..size {
-            let s: <T as ToOwned>::Owned = quickcheck::Arbitrary::arbitrary(g);
-            vec.push(s.borrow());
+    /// Checks whether two vectors contain the same strings the same number of times, in any order.
+    pub fn eq_multiset(&self, other: &Dynamic<T, S>) -> bool
+        where T::Owned: Hash + Eq
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut counts = HashMap::with_capacity(self.len());
+        for s in self {
+            *counts.entry(s.to_owned()).or_insert(0usize) += 1;
         }
+        for s in other {
+            let count = counts.entry(s.to_owned()).or_insert(0);
+            if *count == 0 {
+                return false;
+            }
+            *count -= 1;
+        }
+        true
+    }
 
-        vec
+    /// Converts the vector into a boxed slice of owned strings.
+    pub fn into_boxed_owned(self) -> Box<[<T as ToOwned>::Owned]> {
+        self.to_vec().into_boxed_slice()
     }
 
-    fn shrink(&self) -> Box<Iterator<Item=Dynamic<T>>> {
-        let new_self: Vec<<T as ToOwned>::Owned> = self.iter().map(ToOwned::to_owned).collect();
-        Box::new(new_self.shrink().map(|v| v.iter().map(|s| s.borrow()).collect()))
+    /// Converts the vector into a `Vec` of owned strings in reverse order.
+    ///
+    /// This gives the same strings as calling `pop_off` until the vector is empty.
+    pub fn into_vec_rev(self) -> Vec<<T as ToOwned>::Owned> {
+        self.iter().rev().map(ToOwned::to_owned).collect()
     }
-}
+
+    /// Copies the strings into a `Vec` of owned strings.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<<T as ToOwned>::Owned> {
+        self.iter().map(ToOwned::to_owned).collect()
+    }
+
+    /// Returns the offset into the buffer where the `idx`th string starts.
+    #[inline]
+    fn data_start(&self, idx: usize) -> usize {
+        if idx == 0 { 0 } else { self.split[idx - 1] }
+    }
+
+    /// Returns the split indices of the vector, i.e. the offset into the buffer where each string ends.
+    #[inline]
+    pub fn as_splits(&self) -> &[usize] {
+        &self.split
+    }
+
+    /// Returns the buffer and split indices of the vector.
+    ///
+    /// The split indices are cumulative: each is the offset into the buffer where the
+    /// corresponding string ends, so the last one is the length of the buffer.
+    #[inline]
+    pub fn as_raw_parts(&self) -> (&T::Data, &[usize]) {
+        (self.buffer.as_data(), &self.split)
+    }
+
+    /// Allows editing the split indices of the vector directly, repartitioning the buffer.
+    ///
+    /// The edits only take effect once `SplitsGuard::commit` is called; dropping the guard
+    /// discards them.
+    #[inline]
+    #[must_use = "edits to the split indices are discarded unless `commit` is called"]
+    pub fn splits_mut(&mut self) -> SplitsGuard<T, S> {
+        let split = self.split.clone();
+        SplitsGuard { vec: self, split }
+    }
+
+    /// Checks every string in the vector, returning the index and error of each invalid one.
+    ///
+    /// Strings are always valid unless they've been modified with unsafe code, so this is mostly
+    /// useful for checking data which came from elsewhere.
+    pub fn validate_all(&self) -> Vec<(usize, T::ConvError)> {
+        let buffer = self.buffer.as_data();
+        let split = Split::new(&self.split);
+        (0..split.len())
+            .filter_map(|idx| T::from_data(split.get(idx).index_into(buffer)).err().map(|e| (idx, e)))
+            .collect()
+    }
+
+    /// Checks that the split indices partition the buffer into valid strings.
+    fn check_parts(buffer: &T::Data, split: &[usize]) -> Result<(), RawError<T::ConvError>> {
+        let split = Split::new(split);
+        split.check_exact(buffer.len())?;
+        for idx in 0..split.len() {
+            T::from_data(split.get(idx).index_into(buffer)).map_err(|e| RawError::Data(idx, e))?;
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the vector so that the `i`th string is the `order[i]`th string of the original.
+    fn permute(&mut self, order: &[usize]) {
+        let old_buffer = self.buffer.split_off(0);
+        let old_split = mem::replace(&mut self.split, Vec::with_capacity(order.len()));
+        let split = Split::new(&old_split);
+        for &idx in order {
+            let data = split.get(idx).index_into(old_buffer.as_data());
+            let end = self.split.last().cloned().unwrap_or(0) + data.len();
+            self.buffer.push(data);
+            self.split.push(end);
+        }
+    }
+}
+
+impl<T: StrLike<Data = [u8]> + ?Sized, S: Storage<[u8]>> Dynamic<T, S> {
+    /// Returns a pointer to the bytes of the string at `index` in the buffer, and their length.
+    ///
+    /// For `CStr`s, this includes the nul terminator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn element_ptr_range(&self, index: usize) -> (*const u8, usize) {
+        assert!(index < self.len(), "index {} was out of bounds", index);
+        let data = &self.buffer.as_data()[self.data_start(index)..self.split[index]];
+        (data.as_ptr(), data.len())
+    }
+
+    /// Returns one `IoSlice` per string, pointing into the buffer, for use with vectored writes.
+    ///
+    /// For `CStr`s, each slice includes the nul terminator.
+    pub fn io_slices(&self) -> Vec<io::IoSlice> {
+        let split = Split::new(&self.split);
+        (0..self.len()).map(|idx| io::IoSlice::new(split.get(idx).index_into(self.buffer.as_data()))).collect()
+    }
+
+    /// Returns a 64-bit FNV-1a checksum of each string's bytes, in order.
+    ///
+    /// Unlike `Hash`, the checksums don't depend on the platform or Rust version, so they can be
+    /// stored alongside the data and used to detect corruption of individual strings later.
+    pub fn element_checksums(&self) -> Vec<u64> {
+        let split = Split::new(&self.split);
+        (0..self.len()).map(|idx| {
+            split.get(idx).index_into(self.buffer.as_data()).iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+                (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+            })
+        }).collect()
+    }
+
+    /// Returns the number of bytes `write_binary` will write for this vector.
+    ///
+    /// The output can be read back with `read_binary`.
+    #[inline]
+    pub fn binary_len(&self) -> usize {
+        8 * (1 + self.len()) + self.buffer.as_data().len()
+    }
+
+    /// Writes the vector in a simple binary format: the number of strings, then the split
+    /// indices, then the buffer itself.
+    ///
+    /// The number of strings and the split indices are each written as little-endian `u64`s.
+    /// This format is specific to this crate and unrelated to the `serde` impls.
+    pub fn write_binary<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&(self.len() as u64).to_le_bytes())?;
+        for &idx in &self.split {
+            w.write_all(&(idx as u64).to_le_bytes())?;
+        }
+        w.write_all(self.buffer.as_data())
+    }
+}
+
+impl<T: StrLike<Data = [u8]> + ?Sized> Dynamic<T> {
+    /// Reads a vector in the format written by `write_binary`.
+    ///
+    /// Fails with `io::ErrorKind::InvalidData` if the split indices don't cover the buffer in
+    /// increasing order, or if any of the strings they produce are invalid.
+    pub fn read_binary<R: io::Read>(r: &mut R) -> io::Result<Dynamic<T>> {
+        fn read_usize<R: io::Read>(r: &mut R) -> io::Result<usize> {
+            let mut bytes = [0; 8];
+            r.read_exact(&mut bytes)?;
+            let n = u64::from_le_bytes(bytes);
+            if n > usize::max_value() as u64 {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "index was too large"));
+            }
+            Ok(n as usize)
+        }
+
+        let len = read_usize(r)?;
+        let mut split = Vec::with_capacity(cmp::min(len, 1024));
+        for _ in 0..len {
+            split.push(read_usize(r)?);
+        }
+
+        let data_len = split.last().cloned().unwrap_or(0);
+        let mut buffer = Vec::with_capacity(cmp::min(data_len, 1 << 16));
+        r.by_ref().take(data_len as u64).read_to_end(&mut buffer)?;
+        if buffer.len() < data_len {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "buffer was cut short"));
+        }
+
+        Dynamic::<T>::check_parts(&buffer, &split)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        Ok(Dynamic { buffer: Cow::Owned(buffer), split, marker: PhantomData })
+    }
+}
+
+/// Guard for editing the split indices of a `Dynamic`, returned by `Dynamic::splits_mut`.
+///
+/// Dereferences to the list of split indices. Edits are validated and applied by `commit`, and
+/// discarded if the guard is dropped instead.
+#[must_use = "edits to the split indices are discarded unless `commit` is called"]
+pub struct SplitsGuard<'a, T: 'a + StrLike + ?Sized, S: 'a + Storage<T::Data>> {
+    vec: &'a mut Dynamic<T, S>,
+    split: Vec<usize>,
+}
+
+impl<'a, T: 'a + StrLike + ?Sized, S: 'a + Storage<T::Data>> SplitsGuard<'a, T, S> {
+    /// Applies the edits to the vector.
+    ///
+    /// Fails without modifying the vector if the split indices don't cover the whole buffer in
+    /// increasing order, or if any of the strings they produce are invalid.
+    pub fn commit(self) -> Result<(), RawError<T::ConvError>> {
+        Dynamic::<T, S>::check_parts(self.vec.buffer.as_data(), &self.split)?;
+        self.vec.split = self.split;
+        Ok(())
+    }
+}
+
+impl<'a, T: 'a + StrLike + ?Sized, S: 'a + Storage<T::Data>> Deref for SplitsGuard<'a, T, S> {
+    type Target = Vec<usize>;
+    #[inline]
+    fn deref(&self) -> &Vec<usize> {
+        &self.split
+    }
+}
+
+impl<'a, T: 'a + StrLike + ?Sized, S: 'a + Storage<T::Data>> DerefMut for SplitsGuard<'a, T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<usize> {
+        &mut self.split
+    }
+}
+
+/// Mutable view into part of a `Dynamic`, returned by `Dynamic::split_at_mut`.
+pub struct DynamicMutView<'a, T: 'a + StrLike + ?Sized> {
+    buffer: &'a mut T::Data,
+    split: &'a [usize],
+    offset: usize,
+}
+
+impl<'a, T: 'a + StrLike + ?Sized> DynamicMutView<'a, T> {
+    /// Returns the number of strings in the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.split.len()
+    }
+
+    /// Returns `true` iff the view contains no strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.split.is_empty()
+    }
+
+    /// Returns the position of the `index`th string in the view's part of the buffer.
+    #[inline]
+    fn range(&self, index: usize) -> SplitRange {
+        let start = if index == 0 { self.offset } else { self.split[index - 1] };
+        SplitRange::from(start - self.offset..self.split[index] - self.offset)
+    }
+
+    /// Returns the string at the given index, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            unsafe { Some(T::from_data_unchecked(self.range(index).index_into(&*self.buffer))) }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: 'a + StrLike + StrLikeMut + ?Sized> DynamicMutView<'a, T>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{
+    /// Mutably borrows the string at the given index, or returns `None` if it's out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            let range = self.range(index);
+            unsafe { Some(T::from_data_mut_unchecked(range.index_into_mut(&mut *self.buffer))) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over mutable references to the strings in the view.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut::new(&mut *self.buffer, self.split, self.offset)
+    }
+}
+
+impl Dynamic<str> {
+    /// Creates an empty `Dynamic`.
+    ///
+    /// Unlike `new`, this can be used to initialise constants and statics.
+    #[inline]
+    pub const fn empty() -> Dynamic<str> {
+        Dynamic {
+            buffer: Cow::Borrowed(&[]),
+            split: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts the vector into a vector of C strings, adding a nul terminator to each string.
+    ///
+    /// Fails if any string contains a nul byte; the error holds the first such string.
+    pub fn into_cstring_vec(self) -> Result<CStringVec, NulError> {
+        if let Some(s) = self.iter().find(|s| s.as_bytes().contains(&0)) {
+            return Err(CString::new(s).unwrap_err());
+        }
+
+        let mut buffer = Vec::with_capacity(self.buffer.len() + self.len());
+        let mut split = Vec::with_capacity(self.len());
+        for s in &self {
+            buffer.extend_from_slice(s.as_bytes());
+            buffer.push(0);
+            split.push(buffer.len());
+        }
+        Ok(Dynamic { buffer: Cow::Owned(buffer), split, marker: PhantomData })
+    }
+}
+
+impl<S: Storage<[u8]>> Dynamic<str, S> {
+    /// Returns an iterator over the lines of the string at `index`, as with `str::lines`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn iter_lines(&self, index: usize) -> ::std::str::Lines {
+        self[index].lines()
+    }
+
+    /// Compares the strings at indices `a` and `b`, ignoring ASCII case.
+    pub fn cmp_ignore_ascii_case(&self, a: usize, b: usize) -> Ordering {
+        let lhs = self[a].bytes().map(|c| c.to_ascii_lowercase());
+        let rhs = self[b].bytes().map(|c| c.to_ascii_lowercase());
+        lhs.cmp(rhs)
+    }
+
+    /// Sorts the strings in the vector, ignoring ASCII case.
+    ///
+    /// The sort is stable, and the buffer is only rebuilt once.
+    pub fn sort_by_case_insensitive(&mut self) {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_by(|&a, &b| self.cmp_ignore_ascii_case(a, b));
+        self.permute(&order);
+    }
+}
+
+impl<S: Storage<[u8]>> Dynamic<CStr, S> {
+    /// Returns the string at the given index decoded as UTF-8, or `None` if it's out of bounds.
+    ///
+    /// The string is only copied if it contains invalid UTF-8, which is replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    #[inline]
+    pub fn get_str_lossy(&self, index: usize) -> Option<Cow<str>> {
+        self.get(index).map(CStr::to_string_lossy)
+    }
+}
+
+impl<T: 'static + Copy> Dynamic<[T]> {
+    /// Creates an empty `Dynamic`.
+    ///
+    /// Unlike `new`, this can be used to initialise constants and statics.
+    #[inline]
+    pub const fn empty() -> Dynamic<[T]> {
+        Dynamic {
+            buffer: Cow::Borrowed(&[]),
+            split: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the slices in `src` over the slices starting at `dest`.
+    ///
+    /// The slices starting at `dest` are overwritten one-for-one, so `dest` plus the number of
+    /// copied slices must not exceed the length of the vector. If the overwritten slices have the
+    /// same total length as the copied ones, the data is copied in place; otherwise, the data
+    /// after them is moved to make up for the difference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range of slices is out of bounds.
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        let src = SplitRange::from_bounds(src).to_range(self.len());
+        let count = src.end - src.start;
+        assert!(dest <= self.len() - count, "destination index {} was out of bounds", dest);
+
+        let lens: Vec<usize> = src.clone().map(|i| self.split[i] - self.data_start(i)).collect();
+        let src_data = self.data_start(src.start)..self.data_start(src.end);
+        let dest_data = self.data_start(dest)..self.data_start(dest + count);
+        let src_len = src_data.end - src_data.start;
+        let dest_len = dest_data.end - dest_data.start;
+
+        {
+            let buffer = self.buffer.to_mut();
+            if src_len == dest_len {
+                buffer.copy_within(src_data, dest_data.start);
+            } else {
+                let copied = buffer[src_data].to_vec();
+                buffer.splice(dest_data.clone(), copied);
+            }
+        }
+
+        let mut acc = dest_data.start;
+        for (idx, len) in self.split[dest..dest + count].iter_mut().zip(lens) {
+            acc += len;
+            *idx = acc;
+        }
+        for idx in &mut self.split[dest + count..] {
+            *idx = *idx + src_len - dest_len;
+        }
+    }
+}
+
+impl<E: Copy, T: StrLike<Data = [E]> + ?Sized, S: StorageMut<[E]>> Dynamic<T, S> {
+    /// Adds a string to the front of the vec.
+    ///
+    /// The string is pushed onto the end of the buffer, which is then rotated in place to move it
+    /// to the front. This takes O(n) time, but only reallocates if the buffer is full.
+    pub fn push_front(&mut self, t: &T) {
+        let t = t.to_data();
+        self.buffer.push(t);
+        self.buffer.as_data_mut().rotate_right(t.len());
+        for idx in &mut self.split {
+            *idx += t.len();
+        }
+        self.split.insert(0, t.len());
+    }
+}
+
+impl<T: 'static + Copy, S: StorageMut<[T]>> Dynamic<[T], S> {
+    /// Applies `f` to each slice in the vector, in place.
+    ///
+    /// Because `f` can't change the lengths of the slices, the buffer never has to be rebuilt.
+    pub fn map_in_place<F: FnMut(&mut [T])>(&mut self, mut f: F) {
+        let buffer = self.buffer.as_data_mut();
+        let mut start = 0;
+        for &end in &self.split {
+            f(&mut buffer[start..end]);
+            start = end;
+        }
+    }
+}
+
+impl<S: StorageMut<[u8]>> Dynamic<[u8], S> {
+    /// Rotates the bytes in the buffer left by `n`, wrapping around at the end.
+    ///
+    /// The boundaries between slices move along with the bytes, so every slice ends up intact at
+    /// its new position, except for a slice which contains the `n`th byte. That slice is split in
+    /// two at the wrap-around point, with its tail moving to the front of the buffer and its head
+    /// moving to the end. If `n` lies on a boundary, this just rotates the slices left.
+    pub fn rotate_bytes(&mut self, n: usize) {
+        let total = self.buffer.as_data().len();
+        if total == 0 || n % total == 0 {
+            return;
+        }
+        let n = n % total;
+        self.buffer.as_data_mut().rotate_left(n);
+
+        let mut split = Vec::with_capacity(self.split.len() + 1);
+        split.extend(self.split.iter().filter(|&&end| end > n).map(|&end| end - n));
+        split.extend(self.split.iter().filter(|&&end| end <= n).map(|&end| end + total - n));
+        if split.last() != Some(&total) {
+            split.push(total);
+        }
+        self.split = split;
+    }
+}
+
+impl<T: ?Sized + StrLike, S: Storage<T::Data>> Index<usize> for Dynamic<T, S> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| panic!("index {} was out of bounds", index))
+    }
+}
+
+impl<T: ?Sized + StrLike + StrLikeMut, S: StorageMut<T::Data>> Dynamic<T, S>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{
+    /// Mutably borrows the string at the given index, or returns `None` if it's out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            unsafe {
+                let idx = Split::new(&*self.split).get(index);
+                Some(T::from_data_mut_unchecked(idx.index_into_mut(self.buffer.as_data_mut())))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over mutable references to the strings in the vector.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut::new(self.buffer.as_data_mut(), &*self.split, 0)
+    }
+
+    /// Divides the vector into two mutable views, one of the strings before `index` and one of
+    /// the strings from `index` onward.
+    ///
+    /// The views cover disjoint parts of the buffer, so they can be modified independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the vector.
+    pub fn split_at_mut(&mut self, index: usize) -> (DynamicMutView<T>, DynamicMutView<T>) {
+        assert!(index <= self.len(), "index {} was out of bounds", index);
+        let mid = self.data_start(index);
+        let (left_split, right_split) = self.split.split_at(index);
+        let (left, right) = self.buffer.as_data_mut().split_at_mut(mid);
+        let left = DynamicMutView { buffer: left, split: left_split, offset: 0 };
+        let right = DynamicMutView { buffer: right, split: right_split, offset: mid };
+        (left, right)
+    }
+}
+
+impl<T: ?Sized + StrLike + StrLikeMut, S: StorageMut<T::Data>> IndexMut<usize> for Dynamic<T, S>
+    where T::Data: SplitAtMut<usize>,
+          T::OwnedData: BorrowMut<T::Data>
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).unwrap_or_else(|| panic!("index {} was out of bounds", index))
+    }
+}
+
+impl<T: ?Sized + DataConcat, S: Storage<T::Data>> Dynamic<T, S> {
+    /// Checks whether two vectors are equal, comparing their buffers directly when their strings
+    /// have the same lengths.
+    ///
+    /// This gives the same result as `==`, but is faster for large vectors with identical layouts.
+    pub fn fast_eq(&self, other: &Dynamic<T, S>) -> bool
+        where T: PartialEq,
+              T::Data: PartialEq
+    {
+        if self.split == other.split {
+            self.buffer.as_data() == other.buffer.as_data()
+        } else {
+            self == other
+        }
+    }
+
+    /// Returns all of the strings concatenated together, like `&vec[..]`.
+    ///
+    /// Since the strings share a buffer, this doesn't copy anything.
+    #[inline]
+    pub fn concat(&self) -> &T {
+        unsafe { T::from_data_unchecked(self.buffer.as_data()) }
+    }
+
+    /// Returns the strings in the given range concatenated together.
+    ///
+    /// Unlike indexing, this accepts any kind of range, including `(Bound<usize>, Bound<usize>)`.
+    #[inline]
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> &T {
+        unsafe {
+            let split = Split::new(&*self.split);
+            T::from_data_unchecked(split.get_slice(SplitRange::from_bounds(range)).index_into(self.buffer.as_data()))
+        }
+    }
+
+    /// Joins the strings together into a single owned string, with `sep` between each of them.
+    ///
+    /// Unlike `&vec[..]`, this has to copy the strings.
+    pub fn join(&self, sep: &T) -> <T as ToOwned>::Owned {
+        let sep = sep.to_data();
+        let len = self.buffer.as_data().len() + self.len().saturating_sub(1) * sep.len();
+        let mut data = T::OwnedData::with_capacity(len);
+        for (idx, s) in self.iter().enumerate() {
+            if idx > 0 {
+                data.push_back(sep);
+            }
+            data.push_back(s.to_data());
+        }
+        unsafe { T::from_data_unchecked(data.borrow()) }.to_owned()
+    }
+
+    /// Returns the range of strings whose data overlaps the given range of the buffer.
+    ///
+    /// This maps offsets into `&vec[..]` back to strings; a range that starts or ends partway
+    /// through a string includes that string. An empty range gives an empty range of strings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or past the end of the buffer.
+    pub fn element_range(&self, bytes: Range<usize>) -> Range<usize> {
+        let len = self.buffer.as_data().len();
+        assert!(bytes.start <= bytes.end && bytes.end <= len,
+                "range {:?} was out of bounds for length {}", bytes, len);
+        let split = Split::new(&*self.split);
+        let start = split.element_at(bytes.start);
+        if bytes.start == bytes.end {
+            start..start
+        } else {
+            start..split.element_at(bytes.end - 1) + 1
+        }
+    }
+
+    /// Finds the string containing the given offset into the buffer, returning its index and the
+    /// offset into the string itself, or `None` if the offset is out of bounds.
+    ///
+    /// An offset on the boundary between two strings belongs to the second one; empty strings
+    /// never contain any offsets.
+    pub fn locate_byte(&self, byte: usize) -> Option<(usize, usize)> {
+        let idx = Split::new(&*self.split).element_at(byte);
+        if idx < self.len() {
+            Some((idx, byte - self.data_start(idx)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the strings in each of the given ranges concatenated together, or `None` if any of
+    /// the ranges are out of bounds or decreasing.
+    ///
+    /// The ranges may overlap.
+    pub fn get_ranges(&self, ranges: &[Range<usize>]) -> Option<Vec<&T>> {
+        if ranges.iter().any(|range| range.start > range.end || range.end > self.len()) {
+            return None;
+        }
+        let split = Split::new(&*self.split);
+        Some(ranges.iter().map(|range| unsafe {
+            T::from_data_unchecked(split.get_slice(SplitRange::from(range.clone())).index_into(self.buffer.as_data()))
+        }).collect())
+    }
+
+    /// Re-splits the buffer into strings with the given lengths.
+    ///
+    /// The lengths must add up to the length of the buffer, and each new string must be valid.
+    /// If they aren't, the vector is left unchanged.
+    pub fn resplit_from_lengths(&mut self, lengths: &[usize]) -> Result<(), RawError<T::ConvError>> {
+        let split = lengths.iter().scan(0, |end, &len| {
+            *end += len;
+            Some(*end)
+        }).collect::<Vec<_>>();
+        Dynamic::<T, S>::check_parts(self.buffer.as_data(), &split)?;
+        self.split = split;
+        Ok(())
+    }
+
+    /// Merges `count` strings starting at `start` into a single string.
+    ///
+    /// Since the strings are already next to each other in the buffer, this doesn't move any data.
+    /// Merging zero or one strings does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of strings is out of bounds.
+    pub fn merge_elements(&mut self, start: usize, count: usize) {
+        assert!(start <= self.len() && count <= self.len() - start,
+                "strings {}..{} were out of bounds", start, start + count);
+        if count > 1 {
+            self.split.drain(start..start + count - 1);
+        }
+    }
+
+    /// Splits the string at `index` into several strings at the given offsets into it.
+    ///
+    /// Since the new strings are already next to each other in the buffer, this doesn't move any
+    /// data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, if the offsets are decreasing or past the end of the
+    /// string, or if any of the new strings would be invalid, e.g. when an offset isn't on a
+    /// `char` boundary.
+    pub fn split_element(&mut self, index: usize, offsets: &[usize]) {
+        assert!(index < self.len(), "index {} was out of bounds", index);
+        let start = self.data_start(index);
+        let len = self.split[index] - start;
+        {
+            let data = self.buffer.as_data();
+            let mut prev = 0;
+            for &offset in offsets.iter().chain(Some(&len)) {
+                assert!(prev <= offset && offset <= len, "offset {} was out of order or out of bounds", offset);
+                if let Err(e) = T::from_data(&data[start + prev..start + offset]) {
+                    panic!("offset {} split string {} into invalid parts: {}", offset, index, e)
+                }
+                prev = offset;
+            }
+        }
+        self.split.splice(index..index, offsets.iter().map(|&offset| start + offset));
+    }
+
+    /// Splits the collection into two at the given offset into the buffer.
+    ///
+    /// Strings which end at or before `byte` stay in `self`, and the rest are moved into the
+    /// returned vector. If `byte` falls inside a string, that string is split in two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is out of bounds, or if splitting a string at `byte` would make either
+    /// half invalid, e.g. when `byte` isn't on a `char` boundary.
+    pub fn split_off_bytes(&mut self, byte: usize) -> Dynamic<T, S> {
+        let len = self.split.last().cloned().unwrap_or(0);
+        assert!(byte <= len, "byte offset {} was out of bounds", byte);
+
+        let whole = self.split.iter().take_while(|&&end| end <= byte).count();
+        let straddles = whole < self.len() && self.data_start(whole) < byte;
+        if straddles {
+            let data = self.buffer.as_data();
+            let (start, end) = (self.data_start(whole), self.split[whole]);
+            if let Err(e) = T::from_data(&data[start..byte]).and_then(|_| T::from_data(&data[byte..end])) {
+                panic!("byte offset {} split string {} into invalid halves: {}", byte, whole, e)
+            }
+        }
+
+        let mut new_split = self.split.split_off(whole);
+        for idx in &mut new_split {
+            *idx -= byte;
+        }
+        if straddles {
+            self.split.push(byte);
+        }
+
+        Dynamic {
+            buffer: self.buffer.split_off(byte),
+            split: new_split,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized + DataConcat, S: Storage<T::Data>> Index<Range<usize>> for Dynamic<T, S> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: Range<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat, S: Storage<T::Data>> Index<RangeFrom<usize>> for Dynamic<T, S> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeFrom<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat, S: Storage<T::Data>> Index<RangeTo<usize>> for Dynamic<T, S> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeTo<usize>) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + DataConcat, S: Storage<T::Data>> Index<RangeFull> for Dynamic<T, S> {
+    type Output = T;
+    #[inline]
+    fn index(&self, range: RangeFull) -> &T {
+        self.range(range)
+    }
+}
+
+impl<T: ?Sized + StrLike, S: Storage<T::Data> + Clone> Clone for Dynamic<T, S> {
+    fn clone(&self) -> Dynamic<T, S> {
+        Dynamic {
+            buffer: self.buffer.clone(),
+            split: self.split.clone(),
+            marker: PhantomData,
+        }
+    }
+    fn clone_from(&mut self, source: &Dynamic<T, S>) {
+        self.buffer.clone_from(&source.buffer);
+        self.split.clone_from(&source.split);
+    }
+}
+
+impl<T: ?Sized + StrLike, S: Storage<T::Data>> ::std::hash::Hash for Dynamic<T, S>
+    where T::Data: ::std::hash::Hash
+{
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        // the buffer always ends at the last split index, but slice it anyway so that the hash
+        // explicitly covers only string data and never spare capacity
+        let end = self.split.last().cloned().unwrap_or(0);
+        SplitRange::from(..end).index_into(self.buffer.as_data()).hash(state);
+        self.split.hash(state);
+    }
+}
+
+impl<T: ?Sized + StrLike + PartialEq, S: Storage<T::Data>> PartialEq for Dynamic<T, S> {
+    fn eq(&self, rhs: &Dynamic<T, S>) -> bool {
+        self.iter().eq(rhs.iter())
+    }
+}
+
+impl<'a, T: ?Sized + StrLike + PartialEq, S: Storage<T::Data>> PartialEq<&'a [&'a T]> for Dynamic<T, S> {
+    fn eq(&self, rhs: &&'a [&'a T]) -> bool {
+        self.iter().eq(rhs.iter().cloned())
+    }
+}
+
+impl<'a, T: ?Sized + StrLike + PartialEq, S: Storage<T::Data>> PartialEq<Vec<&'a T>> for Dynamic<T, S> {
+    fn eq(&self, rhs: &Vec<&'a T>) -> bool {
+        self.iter().eq(rhs.iter().cloned())
+    }
+}
+
+/*
+impl<T: ?Sized + StrLike + PartialEq> PartialEq<Vec<T::Owned>> for Dynamic<T, S> {
+    fn eq(&self, rhs: &Vec<T::Owned>) -> bool {
+        self.iter().eq(rhs.iter().map(|s| &*s))
+    }
+}
+*/
+
+impl<T: ?Sized + StrLike + Eq, S: Storage<T::Data>> Eq for Dynamic<T, S> {}
+
+impl<T: ?Sized + StrLike + PartialOrd, S: Storage<T::Data>> PartialOrd for Dynamic<T, S> {
+    fn partial_cmp(&self, rhs: &Dynamic<T, S>) -> Option<Ordering> {
+        self.iter().partial_cmp(rhs.iter())
+    }
+}
+
+impl<'a, T: ?Sized + StrLike + PartialOrd, S: Storage<T::Data>> PartialOrd<&'a [&'a T]> for Dynamic<T, S> {
+    fn partial_cmp(&self, rhs: &&'a [&'a T]) -> Option<Ordering> {
+        self.iter().partial_cmp(rhs.iter().cloned())
+    }
+}
+
+impl<'a, T: ?Sized + StrLike + PartialOrd, S: Storage<T::Data>> PartialOrd<Vec<&'a T>> for Dynamic<T, S> {
+    fn partial_cmp(&self, rhs: &Vec<&'a T>) -> Option<Ordering> {
+        self.iter().partial_cmp(rhs.iter().cloned())
+    }
+}
+
+/*
+impl<T: ?Sized + StrLike + PartialOrd> PartialOrd<Vec<T::Owned>> for Dynamic<T, S> {
+    fn partial_cmp(&self, rhs: &Vec<T::Owned>) -> Option<Ordering> {
+        self.iter().partial_cmp(rhs.iter().map(|s| &*s))
+    }
+}
+*/
+
+impl<T: ?Sized + StrLike + Ord, S: Storage<T::Data>> Ord for Dynamic<T, S> {
+    fn cmp(&self, rhs: &Dynamic<T, S>) -> Ordering {
+        self.iter().cmp(rhs.iter())
+    }
+}
+
+/// Number of strings `Debug` shows before eliding the middle of a vector.
+const DEBUG_LIMIT: usize = 16;
+
+impl<T: ?Sized + StrLike + fmt::Debug, S: Storage<T::Data>> fmt::Debug for Dynamic<T, S> {
+    /// Lists the strings in the vector.
+    ///
+    /// Unless the alternate flag is given, vectors with more than 16 strings only show the first
+    /// and last 8, along with the total count.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        struct Elided(usize);
+        impl fmt::Debug for Elided {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "... ({} strings) ...", self.0)
+            }
+        }
+
+        let len = self.len();
+        if f.alternate() || len <= DEBUG_LIMIT {
+            return f.debug_list().entries(self.iter()).finish();
+        }
+        let half = DEBUG_LIMIT / 2;
+        let mut list = f.debug_list();
+        list.entries(self.iter().take(half));
+        list.entry(&Elided(len));
+        list.entries(self.iter().skip(len - half));
+        list.finish()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: ?Sized + StrLike + Sync, S: Storage<T::Data> + Sync> Dynamic<T, S> {
+    /// Returns a parallel iterator over the strings in the vector.
+    ///
+    /// The strings are split up by index, so any part of the vector can be handed to a different
+    /// thread without copying.
+    #[inline]
+    pub fn par_iter(&self) -> impl ::rayon::iter::IndexedParallelIterator<Item = &T> {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+        (0..self.len()).into_par_iter().map(move |idx| &self[idx])
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: ?Sized + StrLike> quickcheck::Arbitrary for Dynamic<T>
+    where T::Owned: quickcheck::Arbitrary,
+          Dynamic<T>: Send + Sync
+{
+    fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> Dynamic<T> {
+        let mut vec = Dynamic::new();
+
+        let size = g.size();
+        let size = g.gen_range(0, size);
+        for _ in 0..size {
+            let s: <T as ToOwned>::Owned = quickcheck::Arbitrary::arbitrary(g);
+            vec.push(s.borrow());
+        }
+
+        vec
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item=Dynamic<T>>> {
+        let new_self: Vec<<T as ToOwned>::Owned> = self.iter().map(ToOwned::to_owned).collect();
+        Box::new(new_self.shrink().map(|v| v.iter().map(|s| s.borrow()).collect()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized + StrLike + ::serde::Serialize, S: Storage<T::Data>> ::serde::Serialize for Dynamic<T, S> {
+    /// Serializes the vector as a sequence of strings.
+    fn serialize<Z: ::serde::Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ?Sized + StrLike> ::serde::Deserialize<'de> for Dynamic<T>
+    where T::Owned: ::serde::Deserialize<'de>
+{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Dynamic<T>, D::Error> {
+        struct Visitor<T: ?Sized>(PhantomData<fn() -> Box<T>>);
+
+        impl<'de, T: ?Sized + StrLike> ::serde::de::Visitor<'de> for Visitor<T>
+            where T::Owned: ::serde::Deserialize<'de>
+        {
+            type Value = Dynamic<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a sequence of strings")
+            }
+
+            fn visit_seq<A: ::serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Dynamic<T>, A::Error> {
+                // don't trust the hint too much, since it comes from the input
+                let num = cmp::min(seq.size_hint().unwrap_or(0), 4096);
+                let mut vec = Dynamic::with_capacities(num, 0);
+                while let Some(s) = seq.next_element::<<T as ToOwned>::Owned>()? {
+                    vec.push(s.borrow());
+                }
+                Ok(vec)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor(PhantomData))
+    }
+}
+
+/// Compact serde representation for byte-backed `Dynamic`s.
+///
+/// Instead of a sequence of strings, this serializes the vector as a tuple of its buffer and its
+/// split indices, as returned by `as_raw_parts`. It's smaller and faster to read back, but isn't
+/// interoperable with the default format. Use it on a field with
+/// `#[serde(with = "multistr::serde_compact")]`.
+#[cfg(feature = "serde")]
+pub mod serde_compact {
+    use std::cmp;
+    use std::fmt;
+
+    use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+    use {Storage, StrLike};
+    use super::Dynamic;
+
+    struct Bytes<'a>(&'a [u8]);
+
+    impl<'a> Serialize for Bytes<'a> {
+        fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    struct ByteBuf(Vec<u8>);
+
+    impl<'de> Deserialize<'de> for ByteBuf {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ByteBuf, D::Error> {
+            struct BytesVisitor;
+
+            impl<'de> Visitor<'de> for BytesVisitor {
+                type Value = ByteBuf;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("a byte buffer")
+                }
+
+                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<ByteBuf, E> {
+                    Ok(ByteBuf(v.to_vec()))
+                }
+
+                fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<ByteBuf, E> {
+                    Ok(ByteBuf(v))
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ByteBuf, A::Error> {
+                    // don't trust the hint too much, since it comes from the input
+                    let mut buf = Vec::with_capacity(cmp::min(seq.size_hint().unwrap_or(0), 4096));
+                    while let Some(b) = seq.next_element()? {
+                        buf.push(b);
+                    }
+                    Ok(ByteBuf(buf))
+                }
+            }
+
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    /// Serializes the vector as a tuple of its buffer and its split indices.
+    pub fn serialize<T, S, Z>(vec: &Dynamic<T, S>, serializer: Z) -> Result<Z::Ok, Z::Error>
+        where T: ?Sized + StrLike<Data = [u8]>,
+              S: Storage<[u8]>,
+              Z: Serializer
+    {
+        let (buffer, split) = vec.as_raw_parts();
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Bytes(buffer))?;
+        tuple.serialize_element(split)?;
+        tuple.end()
+    }
+
+    /// Deserializes a vector written by `serialize`.
+    ///
+    /// Fails if the split indices don't cover the buffer in increasing order, or if any of the
+    /// strings they produce are invalid.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Dynamic<T>, D::Error>
+        where T: ?Sized + StrLike<Data = [u8]>,
+              D: Deserializer<'de>
+    {
+        let (ByteBuf(buffer), split) = <(ByteBuf, Vec<usize>)>::deserialize(deserializer)?;
+        Dynamic::<T>::check_parts(&buffer, &split).map_err(de::Error::custom)?;
+        Ok(unsafe { Dynamic::from_raw_unchecked(buffer, split) })
+    }
+}
 
 /// Ve of immutable slices stored on the heap in the same buffer.
 pub type SliceVec<T: 'static + Copy> = Dynamic<[T]>;
 
-/// Vec of immutable `str`s stored on the heap in the same buffer.
-pub type StringVec = Dynamic<str>;
+/// Vec of immutable `str`s stored on the heap in the same buffer.
+pub type StringVec = Dynamic<str>;
+
+/// Vec of immutable `CStr`s stored on the heap in the same buffer.
+pub type CStringVec = Dynamic<CStr>;
+
+/// Vec of immutable `OsStr`s stored on the heap in the same buffer.
+#[cfg(unix)]
+pub type OsStringVec = Dynamic<::std::ffi::OsStr>;
+
+/// Vec of immutable `Path`s stored on the heap in the same buffer.
+#[cfg(unix)]
+pub type PathVec = Dynamic<::std::path::Path>;
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::ffi::{CStr, CString};
+    use std::io;
+
+    use super::super::StrLike;
+    use super::{CStringVec, Dynamic, SliceVec, StringVec};
+
+    fn test_cmp<T: ?Sized + StrLike + PartialOrd + ::std::fmt::Debug>(test_slice: &[&T]) {
+        let test_vec = test_slice.to_owned();
+
+        let vec = test_slice.iter().collect::<Dynamic<T>>();
+        let collect = vec.iter().collect::<Vec<_>>();
+
+        assert_eq!(vec, test_slice);
+        assert_eq!(vec, test_vec);
+        assert_eq!(collect, test_vec);
+    }
+
+    #[test]
+    fn slice() {
+        test_cmp::<[u8]>(&[&b"hello"[..], &b"world"[..], &b"123"[..]]);
+    }
+
+    #[test]
+    fn str() {
+        test_cmp::<str>(&["what", "a", "wonderful", "day"]);
+    }
+
+    #[test]
+    fn c_str() {
+        test_cmp::<CStr>(&[CStr::from_bytes_with_nul(&b"just\0"[..]).unwrap(),
+                           CStr::from_bytes_with_nul(&b"testing\0"[..]).unwrap()]);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+
+        assert_send::<StringVec>();
+        assert_sync::<StringVec>();
+        assert_send::<SliceVec<u8>>();
+        assert_sync::<SliceVec<u8>>();
+        assert_send::<CStringVec>();
+        assert_sync::<CStringVec>();
+    }
+
+    #[test]
+    fn empty_static() {
+        static EMPTY: StringVec = StringVec::empty();
+        static EMPTY_SLICE: SliceVec<u32> = SliceVec::empty();
+        assert!(EMPTY.is_empty());
+        assert!(EMPTY.is_borrowed());
+        assert_eq!(&EMPTY[..], "");
+        assert_eq!(EMPTY, StringVec::new());
+        assert!(EMPTY_SLICE.is_empty());
+        assert_eq!(&EMPTY_SLICE[..], &[0u32; 0]);
+    }
+
+    #[test]
+    fn with_capacities() {
+        assert!(<Dynamic<str>>::new().is_borrowed());
+        assert!(<Dynamic<str>>::with_capacities(0, 0).is_borrowed());
+        assert!(<Dynamic<str>>::with_capacities(4, 0).is_borrowed());
+        assert!(!<Dynamic<str>>::with_capacities(0, 4).is_borrowed());
+
+        let mut vec = <Dynamic<str>>::with_capacities(0, 0);
+        vec.push("a");
+        assert!(!vec.is_borrowed());
+    }
+
+    #[test]
+    fn clear_and_release() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        vec.clear_and_release();
+        assert!(vec.is_empty());
+        assert!(vec.is_borrowed());
+        assert_eq!(vec.data_capacity(), 0);
+        assert_eq!(vec.num_capacity(), 0);
+
+        vec.push("a");
+        assert_eq!(vec, vec!["a"]);
+    }
+
+    #[test]
+    fn capacity_remaining() {
+        let mut vec = <Dynamic<str>>::with_capacities(4, 16);
+        assert!(vec.element_capacity_remaining() >= 4);
+        assert!(vec.byte_capacity_remaining() >= 16);
+
+        vec.push("ab");
+        vec.push("cde");
+        assert_eq!(vec.element_capacity_remaining(), vec.num_capacity() - 2);
+        assert_eq!(vec.byte_capacity_remaining(), vec.data_capacity() - 5);
+        assert!(vec.element_capacity_remaining() >= 2);
+        assert!(vec.byte_capacity_remaining() >= 11);
+
+        let vec = <Dynamic<str>>::new();
+        assert_eq!(vec.element_capacity_remaining(), 0);
+        assert_eq!(vec.byte_capacity_remaining(), 0);
+    }
+
+    #[test]
+    fn data_len() {
+        let strings = ["English", "", "Français", "中文"];
+        let vec = strings.iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.data_len(), strings.iter().map(|s| s.len()).sum::<usize>());
+        assert_eq!(vec.data_len(), vec[..].len());
+        assert_eq!(<Dynamic<str>>::new().data_len(), 0);
+    }
+
+    #[test]
+    fn reserve_for_dynamic() {
+        let mut vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        let mut other = ["中文", "Español", "Deutsch"].iter().collect::<Dynamic<str>>();
+        vec.reserve_for_dynamic(&other);
+
+        let num_capacity = vec.num_capacity();
+        let data_capacity = vec.data_capacity();
+        vec.append(&mut other);
+        assert_eq!(vec.num_capacity(), num_capacity);
+        assert_eq!(vec.data_capacity(), data_capacity);
+        assert_eq!(vec, vec!["English", "Français", "中文", "Español", "Deutsch"]);
+    }
+
+    #[test]
+    fn as_raw_parts() {
+        let vec = ["English", "", "中文"].iter().collect::<Dynamic<str>>();
+        let (buffer, split) = vec.as_raw_parts();
+        assert_eq!(buffer, "English中文".as_bytes());
+        assert_eq!(split, &[7, 7, 13]);
+        let vec = <Dynamic<str>>::new();
+        let (buffer, split) = vec.as_raw_parts();
+        assert!(buffer.is_empty() && split.is_empty());
+    }
+
+    #[test]
+    fn splits_mut() {
+        let mut vec = ["ab", "cd", "é"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.as_splits(), &[2, 4, 6]);
+
+        let mut splits = vec.splits_mut();
+        splits[0] = 1;
+        splits.commit().unwrap();
+        assert_eq!(vec, vec!["a", "bcd", "é"]);
+
+        let mut splits = vec.splits_mut();
+        splits[0] = 5;
+        assert!(splits.commit().is_err());
+        let mut splits = vec.splits_mut();
+        splits[1] = 5;
+        assert!(splits.commit().is_err());
+        let mut splits = vec.splits_mut();
+        splits.pop();
+        assert!(splits.commit().is_err());
+        let mut splits = vec.splits_mut();
+        splits.push(7);
+        assert!(splits.commit().is_err());
+        assert_eq!(vec, vec!["a", "bcd", "é"]);
+
+        vec.splits_mut()[0] = 0;
+        assert_eq!(vec, vec!["a", "bcd", "é"]);
+
+        let mut splits = vec.splits_mut();
+        splits.remove(0);
+        splits.commit().unwrap();
+        assert_eq!(vec, vec!["abcd", "é"]);
+    }
+
+    #[test]
+    fn splits_mut_dropped() {
+        let mut vec = ["a", "bcd", "é"].iter().collect::<Dynamic<str>>();
+        {
+            let mut splits = vec.splits_mut();
+            splits[0] = 2;
+            splits.remove(1);
+        }
+        assert_eq!(vec.as_splits(), &[1, 4, 6]);
+        assert_eq!(vec, vec!["a", "bcd", "é"]);
+    }
+
+    #[test]
+    fn validate_all() {
+        let mut vec = ["English", "Français", "中文", "Español"].iter().collect::<Dynamic<str>>();
+        assert!(vec.validate_all().is_empty());
+
+        unsafe {
+            vec[1].as_bytes_mut()[4] = 0xFF;
+            vec[2].as_bytes_mut()[0] = 0xFF;
+        }
+        let errors = vec.validate_all();
+        assert_eq!(errors.iter().map(|&(idx, _)| idx).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn element_ptr_range() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let base = vec[..].as_ptr();
+        assert_eq!(vec.element_ptr_range(0), (base, 7));
+        assert_eq!(vec.element_ptr_range(1), (base.wrapping_add(7), 9));
+        assert_eq!(vec.element_ptr_range(2), (base.wrapping_add(16), 6));
+
+        let vec = [CStr::from_bytes_with_nul(&b"just\0"[..]).unwrap(),
+                   CStr::from_bytes_with_nul(&b"testing\0"[..]).unwrap()]
+            .iter()
+            .collect::<CStringVec>();
+        let (ptr, len) = vec.element_ptr_range(1);
+        assert_eq!(len, 8);
+        assert_eq!(unsafe { CStr::from_ptr(ptr as *const _) }, &vec[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_element_ptr_range_oob() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        vec.element_ptr_range(3);
+    }
+
+    #[test]
+    fn io_slices() {
+        use std::io::Write;
+
+        let vec = ["English", "", "Français", "中文"].iter().collect::<StringVec>();
+        let slices = vec.io_slices();
+        assert_eq!(slices.len(), 4);
+        let mut out = Vec::new();
+        assert_eq!(out.write_vectored(&slices).unwrap(), vec[..].len());
+        assert_eq!(out, vec[..].as_bytes());
+
+        let c_str = CStr::from_bytes_with_nul(b"abc\0").unwrap();
+        let vec = [c_str, c_str].iter().collect::<CStringVec>();
+        let mut out = Vec::new();
+        out.write_vectored(&vec.io_slices()).unwrap();
+        assert_eq!(out, b"abc\0abc\0");
+    }
+
+    #[test]
+    fn element_checksums() {
+        let vec = ["English", "", "Français", "中文"].iter().collect::<StringVec>();
+        let sums = vec.element_checksums();
+        assert_eq!(sums.len(), 4);
+        // FNV-1a offset basis for the empty string, and the published test vector for "a"
+        assert_eq!(sums[1], 0xcbf2_9ce4_8422_2325);
+        assert_eq!(["a"].iter().collect::<StringVec>().element_checksums(), vec![0xaf63_dc4c_8601_ec8c]);
+
+        let changed = ["English", "", "Francais", "中文"].iter().collect::<StringVec>();
+        let changed_sums = changed.element_checksums();
+        let diffs = sums.iter().zip(&changed_sums).filter(|&(a, b)| a != b).count();
+        assert_eq!(diffs, 1);
+        assert_ne!(sums[2], changed_sums[2]);
+        assert!(StringVec::new().element_checksums().is_empty());
+    }
+
+    #[test]
+    fn binary_len() {
+        let vecs = vec![
+            StringVec::new(),
+            ["English", "Français", "中文"].iter().collect::<StringVec>(),
+            ["", ""].iter().collect::<StringVec>(),
+        ];
+        for vec in vecs {
+            let mut out = Vec::new();
+            vec.write_binary(&mut out).unwrap();
+            assert_eq!(vec.binary_len(), out.len());
+        }
+
+        let vec = ["ab", "c"].iter().collect::<StringVec>();
+        let mut out = Vec::new();
+        vec.write_binary(&mut out).unwrap();
+        assert_eq!(out, b"\x02\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x03\0\0\0\0\0\0\0abc");
+    }
+
+    #[test]
+    fn read_binary() {
+        let vecs = vec![
+            StringVec::new(),
+            ["English", "Français", "中文"].iter().collect::<StringVec>(),
+            ["", ""].iter().collect::<StringVec>(),
+        ];
+        for vec in vecs {
+            let mut out = Vec::new();
+            vec.write_binary(&mut out).unwrap();
+            assert_eq!(StringVec::read_binary(&mut &out[..]).unwrap(), vec);
+        }
+
+        let err = StringVec::read_binary(&mut &b"\x01\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0a"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        let err = StringVec::read_binary(&mut &b"\x02\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0ab"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = StringVec::read_binary(&mut &b"\x01\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\xff"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn hash_ignores_capacity() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash(vec: &Dynamic<str>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            vec.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let mut roomy = <Dynamic<str>>::with_capacities(16, 256);
+        roomy.extend(["English", "Français", "中文", "Deutsch"].iter());
+        roomy.pop();
+        assert_eq!(vec, roomy);
+        assert_eq!(hash(&vec), hash(&roomy));
+        assert_eq!(hash(&Dynamic::new()), hash(&Dynamic::with_capacities(4, 4)));
+    }
+
+    #[test]
+    fn debug() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(format!("{:?}", vec), r#"["English", "Français", "中文"]"# )
+    }
+
+    #[test]
+    fn debug_elided() {
+        let strings = (0..100).map(|i| i.to_string()).collect::<Vec<_>>();
+        let vec = strings.iter().map(String::as_str).collect::<Dynamic<str>>();
+        assert_eq!(format!("{:?}", vec),
+                   r#"["0", "1", "2", "3", "4", "5", "6", "7", ... (100 strings) ..., "#.to_string() +
+                   r#""92", "93", "94", "95", "96", "97", "98", "99"]"#);
+        assert_eq!(format!("{:#?}", vec), format!("{:#?}", strings));
+
+        let vec = strings[..16].iter().map(String::as_str).collect::<Dynamic<str>>();
+        assert_eq!(format!("{:?}", vec), format!("{:?}", &strings[..16]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_oob() {
+        let vec = <Dynamic<[u8]>>::new();
+        let _ = &vec[0];
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_oob_str() {
+        let vec = <Dynamic<str>>::new();
+        let _ = &vec[0];
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_oob_c_str() {
+        let vec = <Dynamic<CStr>>::new();
+        let _ = &vec[0];
+    }
+
+    #[test]
+    fn index() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(&vec[0], "English");
+        assert_eq!(&vec[1], "Français");
+        assert_eq!(&vec[2], "中文");
+        assert_eq!(&vec[0..0], "");
+        assert_eq!(&vec[0..1], "English");
+        assert_eq!(&vec[0..2], "EnglishFrançais");
+        assert_eq!(&vec[0..3], "EnglishFrançais中文");
+        assert_eq!(&vec[1..1], "");
+        assert_eq!(&vec[1..2], "Français");
+        assert_eq!(&vec[1..3], "Français中文");
+        assert_eq!(&vec[2..2], "");
+        assert_eq!(&vec[2..3], "中文");
+        assert_eq!(&vec[3..3], "");
+        assert_eq!(&vec[0..], "EnglishFrançais中文");
+        assert_eq!(&vec[1..], "Français中文");
+        assert_eq!(&vec[2..], "中文");
+        assert_eq!(&vec[3..], "");
+        assert_eq!(&vec[..0], "");
+        assert_eq!(&vec[..1], "English");
+        assert_eq!(&vec[..2], "EnglishFrançais");
+        assert_eq!(&vec[..3], "EnglishFrançais中文");
+        assert_eq!(&vec[..], "EnglishFrançais中文");
+    }
+
+    #[test]
+    fn range() {
+        use std::ops::Bound::*;
+
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.range(0..2), &vec[0..2]);
+        assert_eq!(vec.range(1..), &vec[1..]);
+        assert_eq!(vec.range(..2), &vec[..2]);
+        assert_eq!(vec.range(..), &vec[..]);
+        assert_eq!(vec.range((Included(1), Excluded(3))), &vec[1..3]);
+        assert_eq!(vec.range((Excluded(0), Included(1))), &vec[1..2]);
+        assert_eq!(vec.range((Unbounded, Included(0))), &vec[..1]);
+        assert_eq!(vec.range((Excluded(1), Unbounded)), &vec[2..]);
+    }
+
+    #[test]
+    fn get_ranges() {
+        let vec = ["English", "Français", "中文", "Deutsch"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.get_ranges(&[0..1, 2..4]), Some(vec!["English", "中文Deutsch"]));
+        assert_eq!(vec.get_ranges(&[0..3, 1..2, 2..2]), Some(vec!["EnglishFrançais中文", "Français", ""]));
+        assert_eq!(vec.get_ranges(&[]), Some(vec![]));
+        assert_eq!(vec.get_ranges(&[0..1, 3..5]), None);
+        assert_eq!(vec.get_ranges(&[2..1]), None);
+    }
+
+    #[test]
+    fn resplit_from_lengths() {
+        let mut vec = ["abcdef"].iter().collect::<Dynamic<str>>();
+        vec.resplit_from_lengths(&[2, 2, 2]).unwrap();
+        assert_eq!(vec, vec!["ab", "cd", "ef"]);
+        vec.resplit_from_lengths(&[0, 6, 0]).unwrap();
+        assert_eq!(vec, vec!["", "abcdef", ""]);
+        assert!(vec.resplit_from_lengths(&[2, 2]).is_err());
+        assert!(vec.resplit_from_lengths(&[2, 2, 3]).is_err());
+        assert_eq!(vec, vec!["", "abcdef", ""]);
+
+        let mut vec = ["Français"].iter().collect::<Dynamic<str>>();
+        assert!(vec.resplit_from_lengths(&[5, 4]).is_err());
+        vec.resplit_from_lengths(&[4, 5]).unwrap();
+        assert_eq!(vec, vec!["Fran", "çais"]);
+    }
+
+    #[test]
+    fn merge_elements() {
+        let mut vec = ["ab", "cd", "ef"].iter().collect::<Dynamic<str>>();
+        vec.merge_elements(0, 2);
+        assert_eq!(vec, vec!["abcd", "ef"]);
+
+        let mut vec = ["ab", "cd", "ef", "gh"].iter().collect::<Dynamic<str>>();
+        vec.merge_elements(1, 3);
+        assert_eq!(vec, vec!["ab", "cdefgh"]);
+        vec.merge_elements(1, 1);
+        vec.merge_elements(2, 0);
+        assert_eq!(vec, vec!["ab", "cdefgh"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_merge_elements_oob() {
+        let mut vec = ["ab", "cd", "ef"].iter().collect::<Dynamic<str>>();
+        vec.merge_elements(2, 2);
+    }
+
+    #[test]
+    fn split_element() {
+        let mut vec = ["abcd", "ef"].iter().collect::<Dynamic<str>>();
+        vec.split_element(0, &[2]);
+        assert_eq!(vec, vec!["ab", "cd", "ef"]);
+        vec.split_element(2, &[0, 1, 1]);
+        assert_eq!(vec, vec!["ab", "cd", "", "e", "", "f"]);
+        vec.split_element(1, &[]);
+        assert_eq!(vec, vec!["ab", "cd", "", "e", "", "f"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_element_char_boundary() {
+        let mut vec = ["Français"].iter().collect::<Dynamic<str>>();
+        vec.split_element(0, &[5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_element_out_of_order() {
+        let mut vec = ["abcd"].iter().collect::<Dynamic<str>>();
+        vec.split_element(0, &[3, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_element_oob() {
+        let mut vec = ["abcd"].iter().collect::<Dynamic<str>>();
+        vec.split_element(0, &[5]);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let other = vec.split_off(1);
+        assert_eq!(vec, vec!["English"]);
+        assert_eq!(other, vec!["Français", "中文"]);
+        assert_eq!(&other[..], "Français中文");
+
+        let mut vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        let other = vec.split_off(0);
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+        assert_eq!(other, vec!["English", "Français"]);
+    }
+
+    #[test]
+    fn split_off_bytes() {
+        let mut vec = ["abc", "def"].iter().collect::<Dynamic<str>>();
+        let other = vec.split_off_bytes(2);
+        assert_eq!(vec, vec!["ab"]);
+        assert_eq!(other, vec!["c", "def"]);
+
+        let mut vec = ["abc", "def"].iter().collect::<Dynamic<str>>();
+        let other = vec.split_off_bytes(3);
+        assert_eq!(vec, vec!["abc"]);
+        assert_eq!(other, vec!["def"]);
+
+        let mut vec = ["abc", "def"].iter().collect::<Dynamic<str>>();
+        let other = vec.split_off_bytes(0);
+        assert!(vec.is_empty());
+        assert_eq!(other, vec!["abc", "def"]);
+
+        let mut vec = ["abc", "def"].iter().collect::<Dynamic<str>>();
+        let other = vec.split_off_bytes(6);
+        assert_eq!(vec, vec!["abc", "def"]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_off_bytes_char_boundary() {
+        let mut vec = ["Français"].iter().collect::<Dynamic<str>>();
+        vec.split_off_bytes(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_off_bytes_oob() {
+        let mut vec = ["abc", "def"].iter().collect::<Dynamic<str>>();
+        vec.split_off_bytes(7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_oob_nonempty() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let _ = &vec[3];
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_left_oob() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let _ = &vec[4..];
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_right_oob() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let _ = &vec[..4];
+    }
+
+    #[test]
+    fn ord() {
+        let fst = ["aha"].iter().collect::<Dynamic<str>>();
+        let snd = ["ah", "a"].iter().collect::<Dynamic<str>>();
+        let thd = ["a", "ha"].iter().collect::<Dynamic<str>>();
+        let fth = ["a", "a"].iter().collect::<Dynamic<str>>();
+        let slc = &mut [&fst, &snd, &thd, &fth];
+        slc.sort();
+        assert_eq!(slc, &[&fth, &thd, &snd, &fst]);
+    }
+
+    #[test]
+    fn copy_within() {
+        let mut vec = [&b"ab"[..], &b"c"[..], &b"de"[..], &b"f"[..]].iter().collect::<SliceVec<u8>>();
+        vec.copy_within(0..2, 2);
+        assert_eq!(vec, vec![&b"ab"[..], &b"c"[..], &b"ab"[..], &b"c"[..]]);
+
+        let mut vec = [&b"a"[..], &b"bc"[..], &b"def"[..]].iter().collect::<SliceVec<u8>>();
+        vec.copy_within(2.., 0);
+        assert_eq!(vec, vec![&b"def"[..], &b"bc"[..], &b"def"[..]]);
+        vec.copy_within(..1, 1);
+        assert_eq!(vec, vec![&b"def"[..], &b"def"[..], &b"def"[..]]);
+    }
+
+    #[test]
+    fn map_in_place() {
+        let mut vec = [&b"ab"[..], &b""[..], &b"cde"[..]].iter().collect::<SliceVec<u8>>();
+        vec.map_in_place(|s| for b in s { *b ^= 0x20 });
+        assert_eq!(vec, vec![&b"AB"[..], &b""[..], &b"CDE"[..]]);
+    }
+
+    #[test]
+    fn rotate_bytes() {
+        let mut vec = [&b"ab"[..], &b"cde"[..], &b"f"[..]].iter().collect::<SliceVec<u8>>();
+        vec.rotate_bytes(2);
+        assert_eq!(vec, vec![&b"cde"[..], &b"f"[..], &b"ab"[..]]);
+        vec.rotate_bytes(1);
+        assert_eq!(vec, vec![&b"de"[..], &b"f"[..], &b"ab"[..], &b"c"[..]]);
+        vec.rotate_bytes(12);
+        assert_eq!(vec, vec![&b"de"[..], &b"f"[..], &b"ab"[..], &b"c"[..]]);
+        assert_eq!(&vec[..], b"defabc");
+
+        let mut vec = [&b""[..], &b"abc"[..], &b""[..]].iter().collect::<SliceVec<u8>>();
+        vec.rotate_bytes(1);
+        assert_eq!(vec, vec![&b"bc"[..], &b""[..], &b""[..], &b"a"[..]]);
+
+        let mut vec = SliceVec::<u8>::new();
+        vec.rotate_bytes(3);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_copy_within_oob() {
+        let mut vec = [&b"a"[..], &b"bc"[..], &b"def"[..]].iter().collect::<SliceVec<u8>>();
+        vec.copy_within(0..2, 2);
+    }
+
+    #[test]
+    fn insert() {
+        let mut vec = <Dynamic<str>>::new();
+        vec.insert(0, "Français");
+        vec.insert(0, "English");
+        vec.insert(2, "中文");
+        vec.insert(1, "");
+        assert_eq!(vec, vec!["English", "", "Français", "中文"]);
+        assert_eq!(&vec[..], "EnglishFrançais中文");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_insert_oob() {
+        let mut vec = ["English"].iter().collect::<Dynamic<str>>();
+        vec.insert(2, "Français");
+    }
+
+    #[test]
+    fn swap() {
+        let mut vec = ["English", "Français", "中文", "Deutsch"].iter().collect::<Dynamic<str>>();
+        vec.swap(0, 2);
+        assert_eq!(vec, vec!["中文", "Français", "English", "Deutsch"]);
+        vec.swap(3, 1);
+        assert_eq!(vec, vec!["中文", "Deutsch", "English", "Français"]);
+        vec.swap(2, 2);
+        assert_eq!(vec, vec!["中文", "Deutsch", "English", "Français"]);
+        assert_eq!(&vec[..], "中文DeutschEnglishFrançais");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_swap_oob() {
+        let mut vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        vec.swap(0, 2);
+    }
+
+    #[test]
+    fn remove() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.remove(1), "Français");
+        assert_eq!(vec, vec!["English", "中文"]);
+        assert_eq!(vec.remove(1), "中文");
+        assert_eq!(vec.remove(0), "English");
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut vec = ["English", "Français", "中文", "Deutsch"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.swap_remove(1), "Français");
+        assert_eq!(vec, vec!["English", "Deutsch", "中文"]);
+        assert_eq!(vec.swap_remove(2), "中文");
+        assert_eq!(vec, vec!["English", "Deutsch"]);
+        assert_eq!(vec.swap_remove(0), "English");
+        assert_eq!(vec.swap_remove(0), "Deutsch");
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_swap_remove_oob() {
+        let mut vec = ["English"].iter().collect::<Dynamic<str>>();
+        vec.swap_remove(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_remove_oob() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        vec.remove(3);
+    }
+
+    quickcheck! {
+        fn remove_matches_vec(vec: Vec<String>, indices: Vec<usize>) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            for idx in indices {
+                if owned.is_empty() {
+                    break;
+                }
+                let idx = idx % owned.len();
+                if vec.remove(idx) != owned.remove(idx) {
+                    return false;
+                }
+            }
+            vec.iter().eq(owned.iter().map(String::as_str)) && vec[..] == owned.concat()[..]
+        }
+
+        fn insert_matches_vec(vec: Vec<String>, inserts: Vec<(usize, String)>) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            for (idx, s) in inserts {
+                let idx = idx % (owned.len() + 1);
+                vec.insert(idx, &s);
+                owned.insert(idx, s);
+            }
+            vec.iter().eq(owned.iter().map(String::as_str)) && vec[..] == owned.concat()[..]
+        }
+
+        fn swap_remove_matches_vec(vec: Vec<String>, indices: Vec<usize>) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            for idx in indices {
+                if owned.is_empty() {
+                    break;
+                }
+                let idx = idx % owned.len();
+                if vec.swap_remove(idx) != owned.swap_remove(idx) {
+                    return false;
+                }
+            }
+            vec.iter().eq(owned.iter().map(String::as_str)) && vec[..] == owned.concat()[..]
+        }
+
+        fn retain_matches_vec(vec: Vec<String>) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            vec.retain(|s| s.len() % 2 == 0);
+            owned.retain(|s| s.len() % 2 == 0);
+            vec.iter().eq(owned.iter().map(String::as_str)) && vec[..] == owned.concat()[..]
+        }
+
+        fn swap_matches_vec(vec: Vec<String>, swaps: Vec<(usize, usize)>) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            if owned.is_empty() {
+                return true;
+            }
+            for (a, b) in swaps {
+                let (a, b) = (a % owned.len(), b % owned.len());
+                vec.swap(a, b);
+                owned.swap(a, b);
+            }
+            vec.iter().eq(owned.iter().map(String::as_str)) && vec[..] == owned.concat()[..]
+        }
+
+        fn sort_matches_vec(vec: Vec<String>) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            vec.sort();
+            owned.sort();
+            vec.iter().eq(owned.iter().map(String::as_str)) && vec[..] == owned.concat()[..]
+        }
+
+        fn drain_matches_vec(vec: Vec<String>, a: usize, b: usize) -> bool {
+            let mut owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            let (a, b) = (a % (owned.len() + 1), b % (owned.len() + 1));
+            let range = if a < b { a..b } else { b..a };
+            vec.drain(range.clone()).eq(owned.drain(range))
+                && vec.iter().eq(owned.iter().map(String::as_str))
+                && vec[..] == owned.concat()[..]
+        }
+
+        fn split_off_matches_vec(vec: Vec<String>, at: usize) -> bool {
+            let owned = vec;
+            let mut vec = owned.iter().map(String::as_str).collect::<Dynamic<str>>();
+            let at = at % (owned.len() + 1);
+            let other = vec.split_off(at);
+            vec.iter().eq(owned[..at].iter().map(String::as_str))
+                && other.iter().eq(owned[at..].iter().map(String::as_str))
+                && vec[..] == owned[..at].concat()[..]
+                && other[..] == owned[at..].concat()[..]
+        }
+
+        fn pop_off(vec: Dynamic<str>) -> bool {
+            let mut vec = vec;
+
+            let cloned = vec.clone();
+
+            let mut owned = Vec::new();
+            while let Some(item) = vec.pop_off() {
+                owned.push(item);
+            }
+            owned.iter().rev().eq(cloned.iter())
+        }
+
+        fn into_vec_rev_matches_vec(vec: Dynamic<str>) -> bool {
+            let mut owned = Vec::from(vec.clone());
+            owned.reverse();
+            vec.into_vec_rev() == owned
+        }
+
+        fn extend(vec: Vec<String>) -> bool {
+            let mut extend = <Dynamic<str>>::new();
+            extend.extend(vec.iter().map(String::as_str));
+            let collect = vec.iter().map(String::as_str).collect::<Dynamic<str>>();
+            extend == collect
+        }
+    }
+
+    #[test]
+    fn iter_double_ended() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.iter().rev().collect::<Vec<_>>(), vec!["中文", "Français", "English"]);
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.next_back(), Some("中文"));
+        assert_eq!(iter.next(), Some("English"));
+        assert_eq!(iter.next_back(), Some("Français"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let vec = <Dynamic<str>>::new();
+        let mut iter = vec.iter();
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        vec.truncate(3);
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+        vec.truncate(5);
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+        vec.truncate(2);
+        assert_eq!(vec, vec!["English", "Français"]);
+        assert_eq!(&vec[..], "EnglishFrançais");
+        vec.push("Deutsch");
+        assert_eq!(vec, vec!["English", "Français", "Deutsch"]);
+        vec.truncate(1);
+        assert_eq!(&vec[..], "English");
+        vec.truncate(0);
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+    }
 
-/// Vec of immutable `CStr`s stored on the heap in the same buffer.
-pub type CStringVec = Dynamic<CStr>;
+    #[test]
+    fn truncate_front() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        vec.truncate_front(0);
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+        vec.truncate_front(2);
+        assert_eq!(vec, vec!["中文"]);
+        assert_eq!(vec.as_splits(), &["中文".len()]);
+        vec.truncate_front(5);
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+    }
 
-///// Vec of immutable `OsStr`s stored on the heap in the same buffer.
-//pub type OsStringVec = Dynamic<OsStr>;
+    #[test]
+    fn truncate_front_keeps_capacity() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let data_capacity = vec.data_capacity();
+        vec.truncate_front(1);
+        assert_eq!(vec.data_capacity(), data_capacity);
+        assert_eq!(vec.pop_front().as_ref().map(|s| &s[..]), Some("Français"));
+        assert_eq!(vec.data_capacity(), data_capacity);
+        assert_eq!(vec, vec!["中文"]);
+    }
 
-#[cfg(test)]
-mod tests {
-    use std::ffi::CStr;
+    #[test]
+    fn retain_within_bytes() {
+        // "English" is 7 bytes, "Français" 9, "中文" 6
+        let orig = ["English", "Français", "", "中文"].iter().collect::<StringVec>();
+        let mut vec = orig.clone();
+        vec.retain_within_bytes(22);
+        assert_eq!(vec, orig);
+        vec.retain_within_bytes(20);
+        assert_eq!(vec, vec!["Français", "", "中文"]);
+        vec.retain_within_bytes(15);
+        assert_eq!(vec, vec!["Français", "", "中文"]);
+        vec.retain_within_bytes(14);
+        assert_eq!(vec, vec!["", "中文"]);
+        assert_eq!(vec.as_splits(), &[0, 6]);
+        vec.retain_within_bytes(6);
+        assert_eq!(vec, vec!["", "中文"]);
+        vec.retain_within_bytes(0);
+        assert!(vec.is_empty());
+
+        let mut vec = orig.clone();
+        vec.retain_within_bytes(8);
+        assert_eq!(vec, vec!["", "中文"]);
+    }
 
-    use super::super::StrLike;
-    use super::Dynamic;
+    #[test]
+    fn push_front_pop_front() {
+        let mut vec = <Dynamic<str>>::new();
+        vec.push_front("中文");
+        vec.push_front("Français");
+        vec.push_front("English");
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+
+        let mut queue = <Dynamic<str>>::new();
+        queue.push("English");
+        queue.push("Français");
+        assert_eq!(queue.pop_front(), Some("English".to_owned()));
+        queue.push("中文");
+        assert_eq!(queue.pop_front(), Some("Français".to_owned()));
+        assert_eq!(queue.pop_front(), Some("中文".to_owned()));
+        assert_eq!(queue.pop_front(), None);
+        assert_eq!(&queue[..], "");
+
+        let mut deque = ["Français", "中文"].iter().collect::<Dynamic<str>>();
+        deque.push_front("English");
+        assert_eq!(deque.pop_front(), Some("English".to_owned()));
+        deque.push_front("Deutsch");
+        deque.push("Español");
+        assert_eq!(deque, vec!["Deutsch", "Français", "中文", "Español"]);
+        assert_eq!(deque.pop_front(), Some("Deutsch".to_owned()));
+        assert_eq!(deque.pop_front(), Some("Français".to_owned()));
+        deque.push_front("");
+        assert_eq!(deque, vec!["", "中文", "Español"]);
+        assert_eq!(&deque[..], "中文Español");
+    }
 
-    fn test_cmp<T: ?Sized + StrLike + PartialOrd + ::std::fmt::Debug>(test_slice: &[&T]) {
-        let test_vec = test_slice.to_owned();
+    #[test]
+    fn push_front_keeps_allocation() {
+        let mut vec = ["Français", "中文"].iter().collect::<Dynamic<str>>();
+        vec.reserve(1, 7);
+        let data_capacity = vec.data_capacity();
+        vec.push_front("English");
+        assert_eq!(vec.data_capacity(), data_capacity);
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+    }
 
-        let vec = test_slice.iter().collect::<Dynamic<T>>();
-        let collect = vec.iter().collect::<Vec<_>>();
+    #[test]
+    fn first_last() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.first(), Some("English"));
+        assert_eq!(vec.last(), Some("中文"));
+        let vec = ["English"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.first(), Some("English"));
+        assert_eq!(vec.last(), Some("English"));
+        let vec = <Dynamic<str>>::new();
+        assert_eq!(vec.first(), None);
+        assert_eq!(vec.last(), None);
+    }
 
-        assert_eq!(vec, test_slice);
-        assert_eq!(vec, test_vec);
-        assert_eq!(collect, test_vec);
+    #[test]
+    fn sub_vec() {
+        let mut vec = ["English", "Français", "中文", "Deutsch"].iter().collect::<Dynamic<str>>();
+        let sub = vec.sub_vec(1..3);
+        vec.clear();
+        assert_eq!(sub, vec!["Français", "中文"]);
+        assert_eq!(&sub[..], "Français中文");
+        assert_eq!(sub.num_capacity(), 2);
+        assert!(sub.sub_vec(1..1).is_empty());
+        assert_eq!(sub.sub_vec(..), sub);
     }
 
     #[test]
-    fn slice() {
-        test_cmp::<[u8]>(&[&b"hello"[..], &b"world"[..], &b"123"[..]]);
+    #[should_panic]
+    fn panic_sub_vec_oob() {
+        let vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        vec.sub_vec(1..3);
     }
 
     #[test]
-    fn str() {
-        test_cmp::<str>(&["what", "a", "wonderful", "day"]);
+    fn chunk_vecs() {
+        let vec = ["a", "bc", "", "def", "g"].iter().collect::<Dynamic<str>>();
+        let chunks = vec.chunk_vecs(2).collect::<Vec<_>>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0], vec!["a", "bc"]);
+        assert_eq!(chunks[1], vec!["", "def"]);
+        assert_eq!(chunks[2], vec!["g"]);
+        assert_eq!(vec.chunk_vecs(5).collect::<Vec<_>>(), vec![vec.clone()]);
+        assert_eq!(<Dynamic<str>>::new().chunk_vecs(2).count(), 0);
     }
 
     #[test]
-    fn c_str() {
-        test_cmp::<CStr>(&[CStr::from_bytes_with_nul(&b"just\0"[..]).unwrap(),
-                           CStr::from_bytes_with_nul(&b"testing\0"[..]).unwrap()]);
+    #[should_panic]
+    fn panic_chunk_vecs_zero() {
+        let vec = ["a"].iter().collect::<Dynamic<str>>();
+        let _ = vec.chunk_vecs(0);
     }
 
     #[test]
-    fn debug() {
+    fn push_capped() {
+        let mut vec = <Dynamic<str>>::new();
+        assert_eq!(vec.push_capped("English", 2), None);
+        assert_eq!(vec.push_capped("Français", 2), None);
+        assert_eq!(vec.push_capped("中文", 2), Some("English".to_owned()));
+        assert_eq!(vec, vec!["Français", "中文"]);
+        assert_eq!(vec.push_capped("Deutsch", 2), Some("Français".to_owned()));
+        assert_eq!(vec, vec!["中文", "Deutsch"]);
+    }
+
+    #[test]
+    fn retain_range() {
+        let mut vec = ["a", "bc", "", "def", "g"].iter().collect::<Dynamic<str>>();
+        vec.retain_range(1..3);
+        assert_eq!(vec, vec!["bc", ""]);
+        assert_eq!(&vec[..], "bc");
+
+        let mut vec = ["a", "bc", "", "def", "g"].iter().collect::<Dynamic<str>>();
+        vec.retain_range(3..);
+        assert_eq!(vec, vec!["def", "g"]);
+        vec.retain_range(..1);
+        assert_eq!(vec, vec!["def"]);
+        vec.retain_range(1..1);
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_retain_range_oob() {
+        let mut vec = ["a", "bc"].iter().collect::<Dynamic<str>>();
+        vec.retain_range(1..3);
+    }
+
+    #[test]
+    fn drain() {
+        let mut vec = ["English", "", "Français", "中文", "Deutsch"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.drain(1..3).collect::<Vec<_>>(), vec!["", "Français"]);
+        assert_eq!(vec, vec!["English", "中文", "Deutsch"]);
+        assert_eq!(&vec[..], "English中文Deutsch");
+
+        {
+            let mut drain = vec.drain(1..);
+            assert_eq!(drain.len(), 2);
+            assert_eq!(drain.next_back(), Some("Deutsch".to_owned()));
+        }
+        assert_eq!(vec, vec!["English"]);
+        assert_eq!(&vec[..], "English");
+
+        assert_eq!(vec.drain(..).collect::<Vec<_>>(), vec!["English"]);
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+        assert_eq!(vec.drain(..).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_drain_oob() {
+        let mut vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        vec.drain(1..3);
+    }
+
+    #[test]
+    fn get() {
         let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
-        assert_eq!(format!("{:?}", vec), r#"["English", "Français", "中文"]"# )
+        assert_eq!(vec.get(0), Some("English"));
+        assert_eq!(vec.get(2), Some("中文"));
+        assert_eq!(vec.get(3), None);
+        assert_eq!(<Dynamic<str>>::new().get(0), None);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        if let Some(s) = vec.get_mut(1) {
+            s.make_ascii_uppercase();
+        }
+        assert_eq!(vec, vec!["English", "FRANçAIS", "中文"]);
+        assert!(vec.get_mut(3).is_none());
+        assert!(<Dynamic<str>>::new().get_mut(0).is_none());
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut vec = ["English", "", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let mut iter = vec.iter_mut();
+        assert_eq!(iter.len(), 4);
+        for s in &mut iter {
+            s.make_ascii_uppercase();
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(vec, vec!["ENGLISH", "", "FRANçAIS", "中文"]);
+
+        let mut vec = [&b"ab"[..], &b"c"[..]].iter().collect::<SliceVec<u8>>();
+        let mut refs = vec.iter_mut().collect::<Vec<_>>();
+        refs[0][1] = b'x';
+        refs[1][0] = b'y';
+        assert_eq!(&vec[..], b"axy");
+        assert_eq!(<Dynamic<str>>::new().iter_mut().next(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        use super::OsStringVec;
+
+        let names = [OsStr::new("English"), OsStr::from_bytes(b"Fran\xe7ais"), OsStr::new("")];
+        let vec = names.iter().collect::<OsStringVec>();
+        assert_eq!(vec.len(), 3);
+        assert!(vec.iter().eq(names.iter().cloned()));
+        assert_eq!(&vec[1], OsStr::from_bytes(b"Fran\xe7ais"));
+        assert_eq!(vec.to_vec()[0], "English");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path() {
+        use std::path::Path;
+
+        use super::PathVec;
+
+        let paths = [Path::new("/usr/bin/cat"), Path::new("relative/file.txt"), Path::new("")];
+        let vec = paths.iter().collect::<PathVec>();
+        assert!(vec.iter().eq(paths.iter().cloned()));
+        assert_eq!(vec[0].file_name().unwrap(), "cat");
+        assert_eq!(vec.to_vec()[1], Path::new("relative").join("file.txt"));
+    }
+
+    #[test]
+    fn into_cstring_vec() {
+        let vec = ["English", "", "Français"].iter().collect::<StringVec>();
+        let c_vec = vec.clone().into_cstring_vec().unwrap();
+        assert_eq!(c_vec.len(), 3);
+        assert_eq!(c_vec.as_raw_parts(), (&b"English\0\0Fran\xc3\xa7ais\0"[..], &[8, 9, 19][..]));
+        assert!(c_vec.iter().map(|s| s.to_str().unwrap()).eq(vec.iter()));
+        assert!(StringVec::new().into_cstring_vec().unwrap().is_empty());
+
+        let vec = ["English", "Fr\0an", "\0"].iter().collect::<StringVec>();
+        let err = vec.into_cstring_vec().unwrap_err();
+        assert_eq!(err.nul_position(), 2);
+        assert_eq!(err.into_vec(), b"Fr\0an");
+    }
+
+    #[test]
+    fn get_str_lossy() {
+        let valid = CStr::from_bytes_with_nul(b"Fran\xc3\xa7ais\0").unwrap();
+        let invalid = CStr::from_bytes_with_nul(b"Fran\xe7ais\0").unwrap();
+        let vec = [valid, invalid].iter().collect::<CStringVec>();
+        match vec.get_str_lossy(0) {
+            Some(Cow::Borrowed(s)) => assert_eq!(s, "Français"),
+            s => panic!("expected borrowed string, got {:?}", s),
+        }
+        match vec.get_str_lossy(1) {
+            Some(Cow::Owned(s)) => assert_eq!(s, "Fran\u{fffd}ais"),
+            s => panic!("expected owned string, got {:?}", s),
+        }
+        assert_eq!(vec.get_str_lossy(2), None);
+    }
+
+    #[test]
+    fn split_at_mut() {
+        let mut vec = [&b"ab"[..], &b"cde"[..], &b"f"[..]].iter().collect::<SliceVec<u8>>();
+        {
+            let (mut left, mut right) = vec.split_at_mut(1);
+            assert_eq!((left.len(), right.len()), (1, 2));
+            assert_eq!(right.get(0), Some(&b"cde"[..]));
+            assert_eq!(right.get(2), None);
+            left.get_mut(0).unwrap()[0] = b'A';
+            right.get_mut(1).unwrap()[0] = b'F';
+            for s in right.iter_mut() {
+                s[0] += 1;
+            }
+            assert_eq!(left.get_mut(1), None);
+        }
+        assert_eq!(vec, vec![&b"Ab"[..], &b"dde"[..], &b"G"[..]]);
+
+        let (left, right) = vec.split_at_mut(3);
+        assert_eq!((left.len(), right.len()), (3, 0));
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    fn from_raw() {
+        let vec = <Dynamic<str>>::from_raw(&b"EnglishFran\xc3\xa7ais"[..], vec![7, 7, 16]);
+        assert_eq!(vec, vec!["English", "", "Français"]);
+        assert!(vec.is_borrowed());
+        let vec = <Dynamic<str>>::from_raw(b"abc".to_vec(), vec![1, 3]);
+        assert_eq!(vec, vec!["a", "bc"]);
+        assert!(<Dynamic<str>>::from_raw(&b""[..], vec![]).is_empty());
+        let vec = unsafe { <Dynamic<str>>::from_raw_unchecked(&b"abc"[..], vec![2, 3]) };
+        assert_eq!(vec, vec!["ab", "c"]);
+    }
+
+    #[test]
+    fn from_raw_no_copy() {
+        let buffer = b"EnglishFran\xc3\xa7ais".to_vec();
+        let ptr = buffer.as_ptr();
+        let vec = <Dynamic<str>>::from_raw(buffer, vec![7, 16]);
+        assert!(!vec.is_borrowed());
+        assert_eq!(vec.as_raw_parts().0.as_ptr(), ptr);
+
+        let buffer = b"abc".to_vec();
+        let ptr = buffer.as_ptr();
+        let vec = unsafe { <Dynamic<str>>::from_raw_unchecked(buffer, vec![3]) };
+        assert_eq!(vec.as_raw_parts().0.as_ptr(), ptr);
     }
 
     #[test]
     #[should_panic]
-    fn panic_oob() {
-        let vec = <Dynamic<[u8]>>::new();
-        let _ = &vec[0];
+    fn panic_from_raw_incomplete() {
+        <Dynamic<str>>::from_raw(&b"abc"[..], vec![1, 2]);
     }
 
     #[test]
     #[should_panic]
-    fn panic_oob_str() {
-        let vec = <Dynamic<str>>::new();
-        let _ = &vec[0];
+    fn panic_from_raw_not_monotonic() {
+        <Dynamic<str>>::from_raw(&b"abc"[..], vec![2, 1, 3]);
     }
 
     #[test]
     #[should_panic]
-    fn panic_oob_c_str() {
-        let vec = <Dynamic<CStr>>::new();
-        let _ = &vec[0];
+    fn panic_from_raw_char_boundary() {
+        <Dynamic<str>>::from_raw(&b"Fran\xc3\xa7ais"[..], vec![5, 9]);
     }
 
     #[test]
-    fn index() {
+    fn from_iter_reserves() {
+        let strings = (0..100).map(|i| i.to_string()).collect::<Vec<_>>();
+        let vec = strings.iter().map(String::as_str).collect::<Dynamic<str>>();
+        assert!(vec.num_capacity() >= 100 && vec.num_capacity() < 200);
+
+        let mut vec = ["a"].iter().collect::<Dynamic<str>>();
+        vec.extend(strings.iter().map(String::as_str));
+        assert!(vec.num_capacity() >= 101);
+
+        let vec = Vec::<&str>::new().into_iter().collect::<Dynamic<str>>();
+        assert!(vec.is_borrowed());
+    }
+
+    #[test]
+    fn from_iter_owned() {
+        let strings = vec!["English".to_owned(), "Français".to_owned(), "中文".to_owned()];
+        let vec = strings.into_iter().collect::<Dynamic<str>>();
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+
+        let vec = vec![vec![1, 2], vec![], vec![3]].into_iter().collect::<SliceVec<u32>>();
+        assert_eq!(&vec[..], &[1, 2, 3]);
+        assert_eq!(vec.len(), 3);
+
+        let vec = vec![CString::new("abc").unwrap()].into_iter().collect::<CStringVec>();
+        assert_eq!(vec[0].to_bytes(), b"abc");
+    }
+
+    #[test]
+    fn from_joined() {
+        let vec = Dynamic::from_joined(&["a", "b", "c"], "-");
+        assert_eq!(vec, vec!["a", "-", "b", "-", "c"]);
+        assert_eq!(vec.len(), vec.num_capacity());
+        assert_eq!(Dynamic::from_joined(&["a"], "-"), vec!["a"]);
+        assert!(Dynamic::<str>::from_joined(&[], "-").is_empty());
+    }
+
+    #[test]
+    fn concat() {
+        let vec = ["English", "", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.concat(), "EnglishFrançais中文");
+        assert_eq!(vec.concat(), &vec[..]);
+        assert_eq!(<Dynamic<str>>::new().concat(), "");
+    }
+
+    #[test]
+    fn join() {
         let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
-        assert_eq!(&vec[0], "English");
-        assert_eq!(&vec[1], "Français");
-        assert_eq!(&vec[2], "中文");
-        assert_eq!(&vec[0..0], "");
-        assert_eq!(&vec[0..1], "English");
-        assert_eq!(&vec[0..2], "EnglishFrançais");
-        assert_eq!(&vec[0..3], "EnglishFrançais中文");
-        assert_eq!(&vec[1..1], "");
-        assert_eq!(&vec[1..2], "Français");
-        assert_eq!(&vec[1..3], "Français中文");
-        assert_eq!(&vec[2..2], "");
-        assert_eq!(&vec[2..3], "中文");
-        assert_eq!(&vec[3..3], "");
-        assert_eq!(&vec[0..], "EnglishFrançais中文");
-        assert_eq!(&vec[1..], "Français中文");
-        assert_eq!(&vec[2..], "中文");
-        assert_eq!(&vec[3..], "");
-        assert_eq!(&vec[..0], "");
-        assert_eq!(&vec[..1], "English");
-        assert_eq!(&vec[..2], "EnglishFrançais");
-        assert_eq!(&vec[..3], "EnglishFrançais中文");
-        assert_eq!(&vec[..], "EnglishFrançais中文");
+        assert_eq!(vec.join(", "), "English, Français, 中文");
+        assert_eq!(vec.join(""), "EnglishFrançais中文");
+        assert_eq!(vec![""; 3].into_iter().collect::<Dynamic<str>>().join("-"), "--");
+        assert_eq!(vec!["English"].into_iter().collect::<Dynamic<str>>().join(", "), "English");
+        assert_eq!(<Dynamic<str>>::new().join(", "), "");
+
+        let vec = [&[1, 2][..], &[3]].iter().collect::<Dynamic<[u8]>>();
+        assert_eq!(vec.join(&[0]), vec![1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn element_range() {
+        // "EnglishFrançais中文" with strings at 0..7, 7..7, 7..16, 16..22
+        let vec = ["English", "", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.element_range(0..7), 0..1);
+        assert_eq!(vec.element_range(7..16), 2..3);
+        assert_eq!(vec.element_range(3..10), 0..3);
+        assert_eq!(vec.element_range(10..18), 2..4);
+        assert_eq!(vec.element_range(0..22), 0..4);
+        assert_eq!(vec.element_range(8..9), 2..3);
+        assert_eq!(vec.element_range(7..7), 2..2);
+        assert_eq!(vec.element_range(22..22), 4..4);
+        assert_eq!(<Dynamic<str>>::new().element_range(0..0), 0..0);
+    }
+
+    #[test]
+    fn locate_byte() {
+        // "EnglishFrançais中文" with strings at 0..7, 7..7, 7..16, 16..22
+        let vec = ["English", "", "Français", "中文"].iter().collect::<StringVec>();
+        assert_eq!(vec.locate_byte(0), Some((0, 0)));
+        assert_eq!(vec.locate_byte(6), Some((0, 6)));
+        assert_eq!(vec.locate_byte(7), Some((2, 0)));
+        assert_eq!(vec.locate_byte(12), Some((2, 5)));
+        assert_eq!(vec.locate_byte(16), Some((3, 0)));
+        assert_eq!(vec.locate_byte(21), Some((3, 5)));
+        assert_eq!(vec.locate_byte(22), None);
+        assert_eq!(StringVec::new().locate_byte(0), None);
+
+        let pos = vec[..].find("ç").unwrap();
+        let (idx, offset) = vec.locate_byte(pos).unwrap();
+        assert_eq!(&vec[idx][offset..], "çais");
     }
 
     #[test]
     #[should_panic]
-    fn panic_oob_nonempty() {
+    fn element_range_oob() {
+        let vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        vec.element_range(3..17);
+    }
+
+    #[test]
+    fn fast_eq() {
         let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
-        let _ = &vec[3];
+        assert!(vec.fast_eq(&vec.clone()));
+        assert!(!vec.fast_eq(&["English", "Françai", "s中文"].iter().collect()));
+        assert!(!vec.fast_eq(&["English", "Francais", "中文"].iter().collect()));
+        assert!(<Dynamic<str>>::new().fast_eq(&Dynamic::new()));
     }
 
     #[test]
-    #[should_panic]
-    fn panic_left_oob() {
+    fn retain() {
+        let mut vec = ["English", "", "Français", "", "中文"].iter().collect::<Dynamic<str>>();
+        vec.retain(|s| !s.is_empty());
+        assert_eq!(vec, vec!["English", "Français", "中文"]);
+        vec.retain(|s| s.is_ascii());
+        assert_eq!(vec, vec!["English"]);
+        vec.retain(|_| false);
+        assert!(vec.is_empty());
+        assert_eq!(&vec[..], "");
+    }
+
+    #[test]
+    fn retain_keeps_capacity() {
+        let mut vec = ["English", "", "Français", "", "中文"].iter().collect::<Dynamic<str>>();
+        let data_capacity = vec.data_capacity();
+        vec.retain(|s| s != "English");
+        assert_eq!(vec, vec!["", "Français", "", "中文"]);
+        assert_eq!(vec.as_splits(), &[0, 9, 9, 15]);
+        assert_eq!(vec.data_capacity(), data_capacity);
+    }
+
+    #[test]
+    fn sort() {
+        let mut vec = ["中文", "English", "", "Deutsch", "Français"].iter().collect::<Dynamic<str>>();
+        vec.sort();
+        assert_eq!(vec, vec!["", "Deutsch", "English", "Français", "中文"]);
+        assert_eq!(&vec[..], "DeutschEnglishFrançais中文");
+
+        vec.sort_by(|a, b| b.len().cmp(&a.len()));
+        assert_eq!(vec, vec!["Français", "Deutsch", "English", "中文", ""]);
+    }
+
+    #[test]
+    fn reverse() {
+        let orig = ["English", "", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let mut vec = orig.clone();
+        vec.reverse();
+        assert!(vec.iter().eq(orig.iter().rev()));
+        assert_eq!(&vec[..], "中文FrançaisEnglish");
+        vec.reverse();
+        assert_eq!(vec, orig);
+
+        let mut vec = <Dynamic<str>>::new();
+        vec.reverse();
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn contains() {
         let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
-        let _ = &vec[4..];
+        assert!(vec.contains("Français"));
+        assert!(!vec.contains("Franç"));
+        assert!(!vec.contains(""));
+        assert!(!<Dynamic<str>>::new().contains(""));
+        let vec = ["English", ""].iter().collect::<Dynamic<str>>();
+        assert!(vec.contains(""));
     }
 
     #[test]
-    #[should_panic]
-    fn panic_right_oob() {
+    fn binary_search() {
+        let vec = ["", "apple", "banana", "cherry"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.binary_search(""), Ok(0));
+        assert_eq!(vec.binary_search("banana"), Ok(2));
+        assert_eq!(vec.binary_search("cherry"), Ok(3));
+        assert_eq!(vec.binary_search("apricot"), Err(2));
+        assert_eq!(vec.binary_search("a"), Err(1));
+        assert_eq!(vec.binary_search("date"), Err(4));
+        assert_eq!(<Dynamic<str>>::new().binary_search("apple"), Err(0));
+    }
+
+    #[test]
+    fn position() {
+        let vec = ["a", "", "b", ""].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.position(str::is_empty), Some(1));
+        assert_eq!(vec.rposition(str::is_empty), Some(3));
+        assert_eq!(vec.position(|s| s == "a"), Some(0));
+        assert_eq!(vec.rposition(|s| s == "a"), Some(0));
+        assert_eq!(vec.position(|s| s == "c"), None);
+        assert_eq!(<Dynamic<str>>::new().rposition(|_| true), None);
+
+        let mut calls = 0;
+        assert_eq!(vec.position(|_| { calls += 1; true }), Some(0));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn indices_where() {
+        let vec = ["a", "", "b", ""].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.indices_where(str::is_empty), vec![1, 3]);
+        assert_eq!(vec.indices_where(|s| s == "b"), vec![2]);
+        assert!(vec.indices_where(|_| false).is_empty());
+    }
+
+    #[test]
+    fn eq_multiset() {
+        let vec = ["a", "b", "a"].iter().collect::<Dynamic<str>>();
+        assert!(vec.eq_multiset(&["b", "a", "a"].iter().collect()));
+        assert!(!vec.eq_multiset(&["a", "b", "b"].iter().collect()));
+        assert!(!vec.eq_multiset(&["a", "b"].iter().collect()));
+        assert!(<Dynamic<str>>::new().eq_multiset(&Dynamic::new()));
+    }
+
+    #[test]
+    fn iter_len() {
         let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
-        let _ = &vec[..4];
+        let mut iter = vec.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.len(), 0);
     }
 
     #[test]
-    fn ord() {
-        let fst = ["aha"].iter().collect::<Dynamic<str>>();
-        let snd = ["ah", "a"].iter().collect::<Dynamic<str>>();
-        let thd = ["a", "ha"].iter().collect::<Dynamic<str>>();
-        let fth = ["a", "a"].iter().collect::<Dynamic<str>>();
-        let slc = &mut [&fst, &snd, &thd, &fth];
-        slc.sort();
-        assert_eq!(slc, &[&fth, &thd, &snd, &fst]);
+    fn iter_nth() {
+        let vec = ["English", "Français", "中文", "Deutsch"].iter().collect::<Dynamic<str>>();
+        let mut iter = vec.iter();
+        assert_eq!(iter.nth(1), Some("Français"));
+        assert_eq!(iter.nth(0), Some("中文"));
+        assert_eq!(iter.next_back(), Some("Deutsch"));
+        assert_eq!(iter.nth(0), None);
+        assert_eq!(vec.iter().nth(4), None);
+        let mut iter = vec.iter();
+        assert_eq!(iter.nth(usize::max_value()), None);
+        assert_eq!(iter.next(), None);
     }
 
-    quickcheck! {
-        fn pop_off(vec: Dynamic<str>) -> bool {
-            let mut vec = vec;
+    #[test]
+    fn iter_fused() {
+        let vec = ["English"].iter().collect::<Dynamic<str>>();
+        let mut iter = vec.iter().fuse();
+        assert_eq!(iter.next(), Some("English"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
 
-            let cloned = vec.clone();
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 
-            let mut owned = Vec::new();
-            while let Some(item) = vec.pop_off() {
-                owned.push(item);
-            }
-            owned.iter().rev().eq(cloned.iter())
-        }
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.par_iter().len(), 3);
+        assert_eq!(vec.par_iter().map(str::len).sum::<usize>(), vec[..].len());
+        assert_eq!(vec.par_iter().collect::<Vec<_>>(), vec!["English", "Français", "中文"]);
+    }
 
-        fn extend(vec: Vec<String>) -> bool {
-            let mut extend = <Dynamic<str>>::new();
-            extend.extend(vec.iter().map(String::as_str));
-            let collect = vec.iter().map(String::as_str).collect::<Dynamic<str>>();
-            extend == collect
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use serde_json;
+
+        let vec = ["English", "", "Français", "中文"].iter().collect::<StringVec>();
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, r#"["English","","Français","中文"]"#);
+        assert_eq!(serde_json::from_str::<StringVec>(&json).unwrap(), vec);
+
+        let vec = [&[1, 2][..], &[], &[3]].iter().collect::<SliceVec<u8>>();
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, "[[1,2],[],[3]]");
+        assert_eq!(serde_json::from_str::<SliceVec<u8>>(&json).unwrap(), vec);
+
+        let vec = StringVec::new();
+        assert_eq!(serde_json::from_str::<StringVec>(&serde_json::to_string(&vec).unwrap()).unwrap(), vec);
+        assert!(serde_json::from_str::<StringVec>(r#"["a", 1]"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_compact_round_trip() {
+        use serde_json;
+        use super::serde_compact;
+
+        let vec = ["English", "", "Français", "中文"].iter().collect::<StringVec>();
+        let mut json = Vec::new();
+        serde_compact::serialize(&vec, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let mut de = serde_json::Deserializer::from_slice(&json);
+        assert_eq!(serde_compact::deserialize::<str, _>(&mut de).unwrap(), vec);
+
+        let vec = [&b"ab"[..], &b""[..], &b"\xff"[..]].iter().collect::<SliceVec<u8>>();
+        let mut json = Vec::new();
+        serde_compact::serialize(&vec, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let mut de = serde_json::Deserializer::from_slice(&json);
+        assert_eq!(serde_compact::deserialize::<[u8], _>(&mut de).unwrap(), vec);
+
+        let mut de = serde_json::Deserializer::from_str("[[97, 98], [1, 3]]");
+        assert!(serde_compact::deserialize::<str, _>(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str("[[255], [1]]");
+        assert!(serde_compact::deserialize::<str, _>(&mut de).is_err());
+    }
+
+    #[test]
+    fn iter_covariant() {
+        use super::super::Iter;
+
+        fn shorten<'long: 'short, 'short>(i: Iter<'long, str>) -> Iter<'short, str> {
+            i
         }
+
+        let vec = ["English", "Français"].iter().collect::<Dynamic<str>>();
+        let short = shorten((&vec).into_iter());
+        assert_eq!(short.collect::<Vec<_>>(), vec!["English", "Français"]);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.iter_rev().collect::<Vec<_>>(), vec!["中文", "Français", "English"]);
+        assert_eq!(<Dynamic<str>>::new().iter_rev().next(), None);
+    }
+
+    #[test]
+    fn first_difference() {
+        let vec = ["a", "b", "c"].iter().collect::<Dynamic<str>>();
+        let mid = ["a", "x", "c"].iter().collect::<Dynamic<str>>();
+        let short = ["a", "b"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.first_difference(&vec), None);
+        assert_eq!(vec.first_difference(&mid), Some((1, Some("b"), Some("x"))));
+        assert_eq!(vec.first_difference(&short), Some((2, Some("c"), None)));
+        assert_eq!(short.first_difference(&vec), Some((2, None, Some("c"))));
+    }
+
+    #[test]
+    fn to_vec() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let owned: Vec<String> = vec.to_vec();
+        assert_eq!(owned, vec!["English", "Français", "中文"]);
+        assert_eq!(Vec::from(vec), owned);
+
+        let vec = [&b"ab"[..], &b""[..]].iter().collect::<SliceVec<u8>>();
+        let owned: Vec<Vec<u8>> = vec.to_vec();
+        assert_eq!(owned, vec![b"ab".to_vec(), vec![]]);
+        assert_eq!(Vec::from(vec), owned);
+    }
+
+    #[test]
+    fn into_boxed_owned() {
+        let vec = ["English", "Français", "中文"].iter().collect::<Dynamic<str>>();
+        let owned = vec.iter().map(ToOwned::to_owned).collect::<Vec<String>>();
+        assert_eq!(vec.into_boxed_owned(), owned.into_boxed_slice());
+    }
+
+    #[test]
+    fn into_vec_rev() {
+        let vec = ["English", "", "中文"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.into_vec_rev(), vec!["中文", "", "English"]);
+        assert!(<Dynamic<str>>::new().into_vec_rev().is_empty());
+    }
+
+    #[test]
+    fn push_with_separator() {
+        let mut vec = <Dynamic<str>>::new();
+        vec.push_with_separator("/", "a");
+        assert_eq!(vec, vec!["a"]);
+        vec.push_with_separator("/", "b");
+        vec.push_with_separator("/", "c");
+        assert_eq!(vec, vec!["a", "/", "b", "/", "c"]);
+    }
+
+    #[test]
+    fn iter_lines() {
+        let vec = ["header", "a\nb\r\nc\n", ""].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.iter_lines(1).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(vec.iter_lines(0).collect::<Vec<_>>(), vec!["header"]);
+        assert_eq!(vec.iter_lines(2).next(), None);
+    }
+
+    #[test]
+    fn sort_by_case_insensitive() {
+        let mut vec = ["Banana", "apple", "Cherry"].iter().collect::<Dynamic<str>>();
+        assert_eq!(vec.cmp_ignore_ascii_case(0, 1), ::std::cmp::Ordering::Greater);
+        vec.sort_by_case_insensitive();
+        assert_eq!(vec, vec!["apple", "Banana", "Cherry"]);
+
+        let mut vec = ["b", "A", "a", "B"].iter().collect::<Dynamic<str>>();
+        vec.sort_by_case_insensitive();
+        assert_eq!(vec, vec!["A", "a", "b", "B"]);
     }
 
     #[test]